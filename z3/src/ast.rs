@@ -9,6 +9,7 @@ use z3_sys::*;
 use Context;
 use FuncDecl;
 use IsNotApp;
+use Params;
 use Pattern;
 use Sort;
 use SortDiffers;
@@ -70,6 +71,12 @@ pub struct Set<'ctx> {
     pub(crate) z3_ast: Z3_ast,
 }
 
+/// [`Ast`] node representing a regular expression over strings.
+pub struct Regexp<'ctx> {
+    pub(crate) ctx: &'ctx Context,
+    pub(crate) z3_ast: Z3_ast,
+}
+
 /// [`Ast`] node representing a datatype or enumeration value.
 pub struct Datatype<'ctx> {
     pub(crate) ctx: &'ctx Context,
@@ -273,6 +280,20 @@ pub trait Ast<'ctx>: fmt::Debug {
         }
     }
 
+    /// Simplify the `Ast` like [`Ast::simplify()`], using the given
+    /// simplifier `params`.
+    fn simplify_ex(&self, params: &Params<'ctx>) -> Self
+    where
+        Self: Sized,
+    {
+        assert_eq!(self.get_ctx(), params.ctx);
+        unsafe {
+            Self::wrap(self.get_ctx(), {
+                Z3_simplify_ex(self.get_ctx().z3_ctx, self.get_z3_ast(), params.z3_params)
+            })
+        }
+    }
+
     /// Performs substitution on the `Ast`. The slice `substitutions` contains a
     /// list of pairs with a "from" `Ast` that will be substituted by a "to" `Ast`.
     fn substitute<T: Ast<'ctx>>(&self, substitutions: &[(&T, &T)]) -> Self
@@ -359,6 +380,77 @@ pub trait Ast<'ctx>: fmt::Debug {
         self.is_app() && self.num_children() == 0
     }
 
+    /// Return `true` if this is a quantifier, i.e. a `forall`, `exists`
+    /// or `lambda`.
+    fn is_quantifier(&self) -> bool {
+        self.kind() == AstKind::Quantifier
+    }
+
+    /// Return the body of this quantifier. Bound variables appear in it as
+    /// de Bruijn indexed variables.
+    ///
+    /// # Panics
+    ///
+    /// Panics if this `Ast` is not a quantifier.
+    fn quantifier_body(&self) -> Dynamic<'ctx> {
+        assert!(self.is_quantifier(), "ast is not a quantifier");
+        unsafe {
+            Dynamic::wrap(
+                self.get_ctx(),
+                Z3_get_quantifier_body(self.get_ctx().z3_ctx, self.get_z3_ast()),
+            )
+        }
+    }
+
+    /// Return the number of variables bound by this quantifier.
+    ///
+    /// # Panics
+    ///
+    /// Panics if this `Ast` is not a quantifier.
+    fn quantifier_num_bound(&self) -> u32 {
+        assert!(self.is_quantifier(), "ast is not a quantifier");
+        unsafe { Z3_get_quantifier_num_bound(self.get_ctx().z3_ctx, self.get_z3_ast()) }
+    }
+
+    /// Return the name of the `i`-th variable bound by this quantifier, in
+    /// the order the variables were declared.
+    ///
+    /// # Panics
+    ///
+    /// Panics if this `Ast` is not a quantifier, or if `i` is not less than
+    /// [`Ast::quantifier_num_bound()`].
+    fn quantifier_bound_name(&self, i: u32) -> Symbol {
+        assert!(
+            i < self.quantifier_num_bound(),
+            "bound variable index out of range"
+        );
+        unsafe {
+            let z3_ctx = self.get_ctx().z3_ctx;
+            let symbol = Z3_get_quantifier_bound_name(z3_ctx, self.get_z3_ast(), i);
+            Symbol::from_z3_symbol(self.get_ctx(), symbol)
+        }
+    }
+
+    /// Return the sort of the `i`-th variable bound by this quantifier, in
+    /// the order the variables were declared.
+    ///
+    /// # Panics
+    ///
+    /// Panics if this `Ast` is not a quantifier, or if `i` is not less than
+    /// [`Ast::quantifier_num_bound()`].
+    fn quantifier_bound_sort(&self, i: u32) -> Sort<'ctx> {
+        assert!(
+            i < self.quantifier_num_bound(),
+            "bound variable index out of range"
+        );
+        unsafe {
+            Sort::wrap(
+                self.get_ctx(),
+                Z3_get_quantifier_bound_sort(self.get_ctx().z3_ctx, self.get_z3_ast(), i),
+            )
+        }
+    }
+
     /// Return the `FuncDecl` of the `Ast`.
     ///
     /// This will panic if the `Ast` is not an app, i.e. if AstKind is not App
@@ -380,6 +472,8 @@ pub trait Ast<'ctx>: fmt::Debug {
         }
     }
 
+    /// Copy this `Ast` into the context `dest`. The result belongs to
+    /// `dest` and can be used with solvers and other objects of `dest`.
     fn translate<'src_ctx>(&'src_ctx self, dest: &'ctx Context) -> Self
     where
         Self: Sized,
@@ -523,6 +617,8 @@ impl_ast!(Array);
 impl_from_try_into_dynamic!(Array, as_array);
 impl_ast!(Set);
 impl_from_try_into_dynamic!(Set, as_set);
+impl_ast!(Regexp);
+impl_from_try_into_dynamic!(Regexp, as_regexp);
 
 impl<'ctx> Int<'ctx> {
     #[cfg(feature = "arbitrary-size-numeral")]
@@ -553,6 +649,22 @@ impl<'ctx> Real<'ctx> {
         Real::from_real_str(ctx, &num.to_str_radix(10), &den.to_str_radix(10)).unwrap()
     }
 
+    /// Create a real numeral from a string, such as `"42"`, `"3/4"` or
+    /// `"0.125"`. Returns `None` if the string is not a valid numeral.
+    pub fn from_str(ctx: &'ctx Context, value: &str) -> Option<Real<'ctx>> {
+        let sort = Sort::real(ctx);
+        let ast = unsafe {
+            let real_cstring = CString::new(value).ok()?;
+            let numeral_ptr = Z3_mk_numeral(ctx.z3_ctx, real_cstring.as_ptr(), sort.z3_sort);
+            if numeral_ptr.is_null() {
+                return None;
+            }
+
+            numeral_ptr
+        };
+        Some(unsafe { Real::wrap(ctx, ast) })
+    }
+
     pub fn from_real_str(ctx: &'ctx Context, num: &str, den: &str) -> Option<Real<'ctx>> {
         let sort = Sort::real(ctx);
         let ast = unsafe {
@@ -725,6 +837,26 @@ impl<'ctx> Bool<'ctx> {
             })
         }
     }
+    /// Create a constraint that at most `k` of the `values` are true.
+    pub fn atmost(context: &'ctx Context, values: &[&Bool<'ctx>], k: u32) -> Bool<'ctx> {
+        unsafe {
+            Bool::wrap(context, {
+                assert!(values.len() <= 0xffffffff);
+                let values: Vec<Z3_ast> = values.iter().map(|boolean| boolean.z3_ast).collect();
+                Z3_mk_atmost(context.z3_ctx, values.len() as u32, values.as_ptr(), k)
+            })
+        }
+    }
+    /// Create a constraint that at least `k` of the `values` are true.
+    pub fn atleast(context: &'ctx Context, values: &[&Bool<'ctx>], k: u32) -> Bool<'ctx> {
+        unsafe {
+            Bool::wrap(context, {
+                assert!(values.len() <= 0xffffffff);
+                let values: Vec<Z3_ast> = values.iter().map(|boolean| boolean.z3_ast).collect();
+                Z3_mk_atleast(context.z3_ctx, values.len() as u32, values.as_ptr(), k)
+            })
+        }
+    }
 }
 
 impl<'ctx> Int<'ctx> {
@@ -780,6 +912,26 @@ impl<'ctx> Int<'ctx> {
         }
     }
 
+    /// Return the exact decimal representation of this numeral, or `None` if
+    /// it is not a numeral.
+    pub fn as_string(&self) -> Option<std::string::String> {
+        numeral_string(self.ctx, self.z3_ast)
+    }
+
+    /// Create an integer from a real.
+    ///
+    /// The conversion rounds towards negative infinity (floor), so `7/2`
+    /// becomes `3` and `-7/2` becomes `-4`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use z3::{ast, Config, Context};
+    /// # use z3::ast::Ast;
+    /// # let cfg = Config::new();
+    /// # let ctx = Context::new(&cfg);
+    /// let x = ast::Real::from_real(&ctx, -7, 2);
+    /// assert_eq!(ast::Int::from_real(&x).simplify().as_i64(), Some(-4));
+    /// ```
     pub fn from_real(ast: &Real<'ctx>) -> Int<'ctx> {
         unsafe { Self::wrap(ast.ctx, Z3_mk_real2int(ast.ctx.z3_ctx, ast.z3_ast)) }
     }
@@ -823,10 +975,18 @@ impl<'ctx> Int<'ctx> {
     /// Create a bitvector from an integer.
     /// This is just a convenience wrapper around
     /// [`BV::from_int()`]; see notes there.
+    #[deprecated(note = "use `Int::to_bv()` instead")]
     pub fn to_ast(&self, sz: u32) -> BV<'ctx> {
         BV::from_int(self, sz)
     }
 
+    /// Create a bitvector of width `sz` from an integer.
+    /// This is just a convenience wrapper around
+    /// [`BV::from_int()`]; see notes there.
+    pub fn to_bv(&self, sz: u32) -> BV<'ctx> {
+        BV::from_int(self, sz)
+    }
+
     varop! {
         add(Z3_mk_add, Self);
         sub(Z3_mk_sub, Self);
@@ -836,9 +996,19 @@ impl<'ctx> Int<'ctx> {
         unary_minus(Z3_mk_unary_minus, Self);
     }
     binop! {
+        /// Integer division, following SMT-LIB: for a nonzero divisor `b`,
+        /// `a = b * a.div(b) + a.modulo(b)` always holds, so the quotient
+        /// rounds down for positive `b` and up for negative `b`.
         div(Z3_mk_div, Self);
+        /// Integer remainder. This equals [`Int::modulo()`] when `other` is
+        /// positive and its negation when `other` is negative, so the sign
+        /// of a nonzero result follows the divisor.
         rem(Z3_mk_rem, Self);
+        /// Integer modulus, following SMT-LIB: for a nonzero divisor the
+        /// result is always between `0` and `|other| - 1`, so for example
+        /// `(-7) mod 3` is `2`.
         modulo(Z3_mk_mod, Self);
+        /// Raise `self` to the power `other`.
         power(Z3_mk_power, Self);
         lt(Z3_mk_lt, Bool<'ctx>);
         le(Z3_mk_le, Bool<'ctx>);
@@ -905,6 +1075,34 @@ impl<'ctx> Real<'ctx> {
         }
     }
 
+    /// Return the exact rational representation of this numeral, such as
+    /// `"1/3"`, or `None` if it is not a numeral.
+    pub fn as_string(&self) -> Option<std::string::String> {
+        numeral_string(self.ctx, self.z3_ast)
+    }
+
+    /// Return a decimal approximation of this numeral with at most
+    /// `precision` decimal places, or `None` if it is neither a numeral nor
+    /// an algebraic number.
+    ///
+    /// Inexact approximations end with a `?`, e.g. `"1.414?"` for the
+    /// positive root of `x * x = 2` with a precision of 3.
+    pub fn approx(&self, precision: u32) -> Option<std::string::String> {
+        unsafe {
+            if !Z3_is_numeral_ast(self.ctx.z3_ctx, self.z3_ast)
+                && !Z3_is_algebraic_number(self.ctx.z3_ctx, self.z3_ast)
+            {
+                return None;
+            }
+            let bytes = Z3_get_numeral_decimal_string(self.ctx.z3_ctx, self.z3_ast, precision);
+            if bytes.is_null() {
+                None
+            } else {
+                Some(CStr::from_ptr(bytes).to_string_lossy().into_owned())
+            }
+        }
+    }
+
     pub fn from_int(ast: &Int<'ctx>) -> Real<'ctx> {
         unsafe { Self::wrap(ast.ctx, Z3_mk_int2real(ast.ctx.z3_ctx, ast.z3_ast)) }
     }
@@ -1006,6 +1204,16 @@ impl<'ctx> Float<'ctx> {
         }
     }
 
+    // returns RoundingMode to nearest, ties to even
+    pub fn round_nearest_ties_to_even(ctx: &'ctx Context) -> Float<'ctx> {
+        unsafe { Self::wrap(ctx, Z3_mk_fpa_round_nearest_ties_to_even(ctx.z3_ctx)) }
+    }
+
+    // returns RoundingMode to nearest, ties away from zero
+    pub fn round_nearest_ties_to_away(ctx: &'ctx Context) -> Float<'ctx> {
+        unsafe { Self::wrap(ctx, Z3_mk_fpa_round_nearest_ties_to_away(ctx.z3_ctx)) }
+    }
+
     // returns RoundingMode towards zero
     pub fn round_towards_zero(ctx: &'ctx Context) -> Float<'ctx> {
         unsafe { Self::wrap(ctx, Z3_mk_fpa_round_toward_zero(ctx.z3_ctx)) }
@@ -1117,6 +1325,22 @@ impl<'ctx> String<'ctx> {
         concat(Z3_mk_seq_concat, String<'ctx>);
     }
 
+    unop! {
+        /// Returns the length of `Self`
+        length(Z3_mk_seq_length, Int<'ctx>);
+    }
+
+    /// Checks whether `Self` matches the regular expression `re`
+    //
+    // We avoid the binop! macro because the argument has a non-Self type
+    pub fn in_re(&self, re: &Regexp<'ctx>) -> Bool<'ctx> {
+        unsafe {
+            Bool::wrap(self.ctx, {
+                Z3_mk_seq_in_re(self.ctx.z3_ctx, self.z3_ast, re.z3_ast)
+            })
+        }
+    }
+
     binop! {
         /// Checks whether `Self` contains a substring
         contains(Z3_mk_seq_contains, Bool<'ctx>);
@@ -1127,6 +1351,42 @@ impl<'ctx> String<'ctx> {
     }
 }
 
+impl<'ctx> Regexp<'ctx> {
+    /// Creates a regular expression that matches exactly the string `s`
+    ///
+    /// # Panics
+    ///
+    /// Panics if `s` contains a NUL byte.
+    pub fn to_re(ctx: &'ctx Context, s: &str) -> Regexp<'ctx> {
+        let s = String::from_str(ctx, s).unwrap();
+        unsafe { Self::wrap(ctx, Z3_mk_seq_to_re(ctx.z3_ctx, s.z3_ast)) }
+    }
+
+    /// Creates a regular expression that matches any single character
+    /// between `lo` and `hi`, inclusive
+    pub fn range(ctx: &'ctx Context, lo: char, hi: char) -> Regexp<'ctx> {
+        let lo = String::from_str(ctx, lo.encode_utf8(&mut [0; 4])).unwrap();
+        let hi = String::from_str(ctx, hi.encode_utf8(&mut [0; 4])).unwrap();
+        unsafe { Self::wrap(ctx, Z3_mk_re_range(ctx.z3_ctx, lo.z3_ast, hi.z3_ast)) }
+    }
+
+    varop! {
+        /// Creates the union of the argument regular expressions
+        union(Z3_mk_re_union, Self);
+        /// Creates the concatenation of the argument regular expressions
+        concat(Z3_mk_re_concat, Self);
+    }
+
+    unop! {
+        /// Matches zero or more repetitions of `Self`
+        star(Z3_mk_re_star, Self);
+        /// Matches one or more repetitions of `Self`
+        plus(Z3_mk_re_plus, Self);
+        /// Matches `Self` or the empty string
+        option(Z3_mk_re_option, Self);
+    }
+}
+
 macro_rules! bv_overflow_check_signed {
     (
         $(
@@ -1401,6 +1661,28 @@ impl<'ctx> BV<'ctx> {
             })
         }
     }
+
+    /// Rotate the bitvector left by the constant amount `i`, wrapping within its width.
+    ///
+    /// See [`BV::bvrotl()`] for a rotation by a bitvector amount.
+    pub fn rotl(&self, i: u32) -> Self {
+        unsafe {
+            Self::wrap(self.ctx, {
+                Z3_mk_rotate_left(self.ctx.z3_ctx, i, self.z3_ast)
+            })
+        }
+    }
+
+    /// Rotate the bitvector right by the constant amount `i`, wrapping within its width.
+    ///
+    /// See [`BV::bvrotr()`] for a rotation by a bitvector amount.
+    pub fn rotr(&self, i: u32) -> Self {
+        unsafe {
+            Self::wrap(self.ctx, {
+                Z3_mk_rotate_right(self.ctx.z3_ctx, i, self.z3_ast)
+            })
+        }
+    }
 }
 
 impl<'ctx> Array<'ctx> {
@@ -1498,6 +1780,34 @@ impl<'ctx> Array<'ctx> {
             })
         }
     }
+
+    /// Apply the function `f` element-wise to `arrays`, producing an `Array`
+    /// whose value at each index is `f` applied to the values of `arrays` at
+    /// that index.
+    ///
+    /// The arity of `f` must equal the number of `arrays`, and the `i`-th
+    /// domain sort of `f` must equal the range sort of `arrays[i]`.
+    pub fn map(f: &FuncDecl<'ctx>, arrays: &[&Array<'ctx>]) -> Array<'ctx> {
+        assert_eq!(f.arity(), arrays.len());
+        for (i, a) in arrays.iter().enumerate() {
+            assert_eq!(a.ctx, f.ctx);
+            let domain =
+                unsafe { Sort::wrap(f.ctx, Z3_get_domain(f.ctx.z3_ctx, f.z3_func_decl, i as u32)) };
+            assert_eq!(a.get_sort().array_range(), Some(domain));
+        }
+
+        let arrays: Vec<_> = arrays.iter().map(|a| a.z3_ast).collect();
+        unsafe {
+            Self::wrap(f.ctx, {
+                Z3_mk_map(
+                    f.ctx.z3_ctx,
+                    f.z3_func_decl,
+                    arrays.len().try_into().unwrap(),
+                    arrays.as_ptr(),
+                )
+            })
+        }
+    }
 }
 
 impl<'ctx> Set<'ctx> {
@@ -1530,6 +1840,11 @@ impl<'ctx> Set<'ctx> {
         unsafe { Self::wrap(ctx, Z3_mk_empty_set(ctx.z3_ctx, domain.z3_sort)) }
     }
 
+    /// Creates a set that maps the domain to true by default
+    pub fn full(ctx: &'ctx Context, domain: &Sort<'ctx>) -> Set<'ctx> {
+        unsafe { Self::wrap(ctx, Z3_mk_full_set(ctx.z3_ctx, domain.z3_sort)) }
+    }
+
     /// Add an element to the set.
     ///
     /// Note that the `element` _must be_ of the `Set`'s `eltype` sort.
@@ -1601,6 +1916,19 @@ impl<'ctx> Dynamic<'ctx> {
         unsafe { Self::wrap(ast.get_ctx(), ast.get_z3_ast()) }
     }
 
+    /// Create a fresh constant of the given `sort`, whose name starts with
+    /// `prefix` and is guaranteed not to clash with any other declaration.
+    pub fn fresh_const(ctx: &'ctx Context, prefix: &str, sort: &Sort<'ctx>) -> Dynamic<'ctx> {
+        assert_eq!(ctx, sort.ctx);
+        unsafe {
+            Self::wrap(ctx, {
+                let pp = CString::new(prefix).unwrap();
+                let p = pp.as_ptr();
+                Z3_mk_fresh_const(ctx.z3_ctx, p, sort.z3_sort)
+            })
+        }
+    }
+
     pub fn sort_kind(&self) -> SortKind {
         unsafe { Z3_get_sort_kind(self.ctx.z3_ctx, Z3_get_sort(self.ctx.z3_ctx, self.z3_ast)) }
     }
@@ -1685,6 +2013,14 @@ impl<'ctx> Dynamic<'ctx> {
         }
     }
 
+    /// Returns `None` if the `Dynamic` is not actually a `Regexp`
+    pub fn as_regexp(&self) -> Option<Regexp<'ctx>> {
+        match self.sort_kind() {
+            SortKind::RE => Some(unsafe { Regexp::wrap(self.ctx, self.z3_ast) }),
+            _ => None,
+        }
+    }
+
     /// Returns `None` if the `Dynamic` is not actually a `Datatype`
     pub fn as_datatype(&self) -> Option<Datatype<'ctx>> {
         match self.sort_kind() {
@@ -1842,6 +2178,65 @@ pub fn exists_const<'ctx>(
     }
 }
 
+/// Create a lambda expression.
+///
+/// The result is an `Array` whose domain is given by the sorts of the
+/// `bounds` and whose range is the sort of `body`. Selecting from it at an
+/// index substitutes the index for the bound constant in `body`.
+///
+/// `bounds` must be non-empty.
+///
+/// # Examples
+/// ```
+/// # use z3::{ast, Config, Context, SatResult, Solver};
+/// # use z3::ast::Ast;
+/// # let cfg = Config::new();
+/// # let ctx = Context::new(&cfg);
+/// # let solver = Solver::new(&ctx);
+/// let x = ast::Int::new_const(&ctx, "x");
+/// let double = ast::lambda_const(&ctx, &[&x], &ast::Int::add(&ctx, &[&x, &x]));
+///
+/// let y = double.select(&ast::Int::from_i64(&ctx, 21));
+/// solver.assert(&y._eq(&ast::Int::from_i64(&ctx, 42).into()).not());
+/// assert_eq!(solver.check(), SatResult::Unsat);
+/// ```
+pub fn lambda_const<'ctx, T>(ctx: &'ctx Context, bounds: &[&dyn Ast<'ctx>], body: &T) -> Array<'ctx>
+where
+    T: Ast<'ctx>,
+{
+    assert!(!bounds.is_empty());
+    assert!(bounds.iter().all(|a| a.get_ctx() == ctx));
+    assert_eq!(ctx, body.get_ctx());
+
+    let bounds: Vec<_> = bounds.iter().map(|a| a.get_z3_ast()).collect();
+
+    unsafe {
+        Array::wrap(ctx, {
+            Z3_mk_lambda_const(
+                ctx.z3_ctx,
+                bounds.len().try_into().unwrap(),
+                bounds.as_ptr() as *const Z3_app,
+                body.get_z3_ast(),
+            )
+        })
+    }
+}
+
+/// Decimal string of a numeral AST, or `None` if `z3_ast` is not a numeral.
+fn numeral_string(ctx: &Context, z3_ast: Z3_ast) -> Option<std::string::String> {
+    unsafe {
+        if !Z3_is_numeral_ast(ctx.z3_ctx, z3_ast) {
+            return None;
+        }
+        let bytes = Z3_get_numeral_string(ctx.z3_ctx, z3_ast);
+        if bytes.is_null() {
+            None
+        } else {
+            Some(CStr::from_ptr(bytes).to_string_lossy().into_owned())
+        }
+    }
+}
+
 impl IsNotApp {
     pub fn new(kind: AstKind) -> Self {
         Self { kind }
@@ -0,0 +1,98 @@
+use std::ffi::CStr;
+use std::fmt;
+
+use z3_sys::*;
+
+use ast::{Ast, Dynamic};
+use AstMap;
+use AstVector;
+use Context;
+
+impl<'ctx> AstMap<'ctx> {
+    /// Create a new, empty map.
+    pub fn new(ctx: &'ctx Context) -> AstMap<'ctx> {
+        unsafe {
+            let z3_ast_map = Z3_mk_ast_map(ctx.z3_ctx);
+            Z3_ast_map_inc_ref(ctx.z3_ctx, z3_ast_map);
+            AstMap { ctx, z3_ast_map }
+        }
+    }
+
+    /// Return the number of keys in the map.
+    pub fn len(&self) -> usize {
+        unsafe { Z3_ast_map_size(self.ctx.z3_ctx, self.z3_ast_map) as usize }
+    }
+
+    /// Return `true` if the map has no keys.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Map `key` to `value`, replacing any previous value for `key`.
+    pub fn insert(&mut self, key: &impl Ast<'ctx>, value: &impl Ast<'ctx>) {
+        assert_eq!(self.ctx, key.get_ctx());
+        assert_eq!(self.ctx, value.get_ctx());
+        unsafe {
+            Z3_ast_map_insert(
+                self.ctx.z3_ctx,
+                self.z3_ast_map,
+                key.get_z3_ast(),
+                value.get_z3_ast(),
+            )
+        }
+    }
+
+    /// Return `true` if the map contains `key`.
+    pub fn contains(&self, key: &impl Ast<'ctx>) -> bool {
+        unsafe { Z3_ast_map_contains(self.ctx.z3_ctx, self.z3_ast_map, key.get_z3_ast()) }
+    }
+
+    /// Return the value associated with `key`, or `None` if the map does
+    /// not contain `key`.
+    pub fn find(&self, key: &impl Ast<'ctx>) -> Option<Dynamic<'ctx>> {
+        if !self.contains(key) {
+            return None;
+        }
+        unsafe {
+            Some(Dynamic::wrap(
+                self.ctx,
+                Z3_ast_map_find(self.ctx.z3_ctx, self.z3_ast_map, key.get_z3_ast()),
+            ))
+        }
+    }
+
+    /// Remove `key` from the map.
+    pub fn erase(&mut self, key: &impl Ast<'ctx>) {
+        unsafe { Z3_ast_map_erase(self.ctx.z3_ctx, self.z3_ast_map, key.get_z3_ast()) }
+    }
+
+    /// Return the keys stored in the map.
+    pub fn keys(&self) -> AstVector<'ctx> {
+        unsafe { AstVector::wrap(self.ctx, Z3_ast_map_keys(self.ctx.z3_ctx, self.z3_ast_map)) }
+    }
+}
+
+impl<'ctx> fmt::Display for AstMap<'ctx> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        let p = unsafe { Z3_ast_map_to_string(self.ctx.z3_ctx, self.z3_ast_map) };
+        if p.is_null() {
+            return Result::Err(fmt::Error);
+        }
+        match unsafe { CStr::from_ptr(p) }.to_str() {
+            Ok(s) => write!(f, "{}", s),
+            Err(_) => Result::Err(fmt::Error),
+        }
+    }
+}
+
+impl<'ctx> fmt::Debug for AstMap<'ctx> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        <Self as fmt::Display>::fmt(self, f)
+    }
+}
+
+impl<'ctx> Drop for AstMap<'ctx> {
+    fn drop(&mut self) {
+        unsafe { Z3_ast_map_dec_ref(self.ctx.z3_ctx, self.z3_ast_map) };
+    }
+}
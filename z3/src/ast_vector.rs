@@ -0,0 +1,97 @@
+use std::ffi::CStr;
+use std::fmt;
+
+use z3_sys::*;
+
+use ast::{Ast, Dynamic};
+use AstVector;
+use Context;
+
+impl<'ctx> AstVector<'ctx> {
+    pub(crate) unsafe fn wrap(ctx: &'ctx Context, z3_ast_vector: Z3_ast_vector) -> AstVector<'ctx> {
+        Z3_ast_vector_inc_ref(ctx.z3_ctx, z3_ast_vector);
+        AstVector { ctx, z3_ast_vector }
+    }
+
+    /// Create a new, empty vector.
+    pub fn new(ctx: &'ctx Context) -> AstVector<'ctx> {
+        unsafe { Self::wrap(ctx, Z3_mk_ast_vector(ctx.z3_ctx)) }
+    }
+
+    /// Return the number of elements in the vector.
+    pub fn len(&self) -> usize {
+        unsafe { Z3_ast_vector_size(self.ctx.z3_ctx, self.z3_ast_vector) as usize }
+    }
+
+    /// Return `true` if the vector has no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Return the element at position `i`, or `None` if `i` is out of
+    /// bounds.
+    pub fn get(&self, i: usize) -> Option<Dynamic<'ctx>> {
+        if i >= self.len() {
+            return None;
+        }
+        unsafe {
+            Some(Dynamic::wrap(
+                self.ctx,
+                Z3_ast_vector_get(self.ctx.z3_ctx, self.z3_ast_vector, i as u32),
+            ))
+        }
+    }
+
+    /// Add `ast` to the end of the vector.
+    pub fn push(&mut self, ast: &impl Ast<'ctx>) {
+        assert_eq!(self.ctx, ast.get_ctx());
+        unsafe { Z3_ast_vector_push(self.ctx.z3_ctx, self.z3_ast_vector, ast.get_z3_ast()) }
+    }
+
+    /// Iterate over the elements of the vector.
+    pub fn iter<'a>(&'a self) -> impl Iterator<Item = Dynamic<'ctx>> + 'a {
+        (0..self.len()).map(move |i| unsafe {
+            Dynamic::wrap(
+                self.ctx,
+                Z3_ast_vector_get(self.ctx.z3_ctx, self.z3_ast_vector, i as u32),
+            )
+        })
+    }
+}
+
+/// Cloning an `AstVector` copies its elements into a new vector, so that
+/// pushing onto the clone does not affect the original.
+impl<'ctx> Clone for AstVector<'ctx> {
+    fn clone(&self) -> Self {
+        let mut copy = Self::new(self.ctx);
+        for ast in self.iter() {
+            copy.push(&ast);
+        }
+        copy
+    }
+}
+
+impl<'ctx> fmt::Display for AstVector<'ctx> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        let p = unsafe { Z3_ast_vector_to_string(self.ctx.z3_ctx, self.z3_ast_vector) };
+        if p.is_null() {
+            return Result::Err(fmt::Error);
+        }
+        match unsafe { CStr::from_ptr(p) }.to_str() {
+            Ok(s) => write!(f, "{}", s),
+            Err(_) => Result::Err(fmt::Error),
+        }
+    }
+}
+
+impl<'ctx> fmt::Debug for AstVector<'ctx> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        <Self as fmt::Display>::fmt(self, f)
+    }
+}
+
+impl<'ctx> Drop for AstVector<'ctx> {
+    fn drop(&mut self) {
+        unsafe { Z3_ast_vector_dec_ref(self.ctx.z3_ctx, self.z3_ast_vector) };
+    }
+}
@@ -35,6 +35,7 @@ impl Config {
     /// # See also
     ///
     /// - [`Config::set_bool_param_value()`]
+    /// - [`Config::set_u32_param_value()`]
     pub fn set_param_value(&mut self, k: &str, v: &str) {
         let ks = CString::new(k).unwrap();
         let vs = CString::new(v).unwrap();
@@ -59,6 +60,17 @@ impl Config {
         self.set_param_value(k, if v { "true" } else { "false" });
     }
 
+    /// Set an unsigned integer configuration parameter.
+    ///
+    /// This is a helper function.
+    ///
+    /// # See also
+    ///
+    /// - [`Config::set_param_value()`]
+    pub fn set_u32_param_value(&mut self, k: &str, v: u32) {
+        self.set_param_value(k, &v.to_string());
+    }
+
     /// Enable or disable proof generation.
     ///
     /// # See also
@@ -79,15 +91,43 @@ impl Config {
         self.set_bool_param_value("model", b);
     }
 
+    /// Enable or disable debugging of reference counting in Z3.
     pub fn set_debug_ref_count(&mut self, b: bool) {
         self.set_bool_param_value("debug_ref_count", b);
     }
 
+    /// Set the default timeout, in milliseconds, for solvers created in
+    /// contexts using this configuration.
+    ///
+    /// # See also
+    ///
+    /// - [`Solver::check()`](crate::Solver::check)
     pub fn set_timeout_msec(&mut self, ms: u64) {
         self.set_param_value("timeout", &format!("{}", ms));
     }
 }
 
+/// Cloning a `Config` creates an independent configuration with the same
+/// parameters, which can be used to create sibling contexts, e.g. one per
+/// thread. Objects can then be moved between those contexts with the
+/// `translate` methods, such as [`Ast::translate()`](crate::ast::Ast::translate).
+impl Clone for Config {
+    fn clone(&self) -> Self {
+        let mut cfg = Config::new();
+        for (k, v) in &self.kvs {
+            cfg.kvs.push((k.clone(), v.clone()));
+            unsafe {
+                Z3_set_param_value(
+                    cfg.z3_cfg,
+                    cfg.kvs.last().unwrap().0.as_ptr(),
+                    cfg.kvs.last().unwrap().1.as_ptr(),
+                )
+            };
+        }
+        cfg
+    }
+}
+
 impl Default for Config {
     fn default() -> Self {
         Self::new()
@@ -1,7 +1,13 @@
+use ast;
+use std::ffi::{CStr, CString};
+use std::fmt;
+use std::ptr;
 use z3_sys::*;
+use AstVector;
 use Config;
 use Context;
 use ContextHandle;
+use Z3Error;
 
 impl Context {
     pub fn new(cfg: &Config) -> Context {
@@ -15,11 +21,96 @@ impl Context {
         }
     }
 
+    /// Return `Err(_)` if the last call into Z3 on this context reported an
+    /// error.
+    ///
+    /// Contexts are created with an error handler that does not abort, so
+    /// failing Z3 calls only record an error code. That code is reset by the
+    /// next call into Z3, so this must be called right after the call that
+    /// may have failed.
+    pub fn check_error(&self) -> Result<(), Z3Error> {
+        let code = unsafe { Z3_get_error_code(self.z3_ctx) };
+        if code == ErrorCode::OK {
+            return Ok(());
+        }
+        let message = unsafe { CStr::from_ptr(Z3_get_error_msg(self.z3_ctx, code)) }
+            .to_str()
+            .unwrap_or("Couldn't retrieve error message from z3: got invalid UTF-8")
+            .to_owned();
+        Err(Z3Error { code, message })
+    }
+
     /// Interrupt a solver performing a satisfiability test, a tactic processing a goal, or simplify functions.
+    ///
+    /// Only solver checks already in progress are interrupted; an interrupted
+    /// [`Solver::check()`](crate::Solver::check) returns
+    /// [`SatResult::Unknown`](crate::SatResult::Unknown), and later checks
+    /// run normally. Until the next check starts, however, Z3 leaves the
+    /// context cancelled, so for example
+    /// [`Model::eval()`](crate::Model::eval) returns `None`.
+    ///
+    /// To interrupt from another thread, use [`Context::handle()`].
     pub fn interrupt(&self) {
         self.handle().interrupt()
     }
 
+    /// Parse the given string using the SMT-LIB2 parser and return the
+    /// assertions in scope at the end of the string.
+    ///
+    /// Returns `Err(_)` with the parser's error message if the string could
+    /// not be parsed.
+    ///
+    /// # See also:
+    ///
+    /// - [`Solver::to_smt2()`](crate::Solver::to_smt2)
+    pub fn from_smt2(&self, source: &str) -> Result<Vec<ast::Bool<'_>>, String> {
+        let source = CString::new(source).map_err(|e| e.to_string())?;
+        unsafe {
+            let z3_assertions = Z3_parse_smtlib2_string(
+                self.z3_ctx,
+                source.as_ptr(),
+                0,
+                ptr::null(),
+                ptr::null(),
+                0,
+                ptr::null(),
+                ptr::null(),
+            );
+            self.check_error().map_err(|e| e.to_string())?;
+            if z3_assertions.is_null() {
+                return Err(String::from("Z3_parse_smtlib2_string returned null"));
+            }
+            let assertions = AstVector::wrap(self, z3_assertions);
+            Ok(assertions.iter().map(|a| a.as_bool().unwrap()).collect())
+        }
+    }
+
+    /// Update a parameter of this context after it was created.
+    ///
+    /// Only objects created after the update, such as new solvers, are
+    /// affected.
+    ///
+    /// # See also
+    ///
+    /// - [`Config::set_param_value()`]
+    /// - [`Context::update_bool_param_value()`]
+    pub fn update_param_value(&self, k: &str, v: &str) {
+        let ks = CString::new(k).unwrap();
+        let vs = CString::new(v).unwrap();
+        unsafe { Z3_update_param_value(self.z3_ctx, ks.as_ptr(), vs.as_ptr()) };
+    }
+
+    /// Update a parameter of this context after it was created.
+    ///
+    /// This is a helper function.
+    ///
+    /// # See also
+    ///
+    /// - [`Context::update_param_value()`]
+    pub fn update_bool_param_value(&self, k: &str, v: bool) {
+        self.update_param_value(k, if v { "true" } else { "false" });
+    }
+
     /// Obtain a handle that can be used to interrupt computation from another thread.
     ///
     /// # See also:
@@ -48,3 +139,21 @@ impl Drop for Context {
         unsafe { Z3_del_context(self.z3_ctx) };
     }
 }
+
+impl Z3Error {
+    /// The error code reported by Z3.
+    pub fn code(&self) -> ErrorCode {
+        self.code
+    }
+
+    /// The message Z3 associates with the error.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl fmt::Display for Z3Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        write!(f, "{}", self.message)
+    }
+}
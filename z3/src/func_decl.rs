@@ -1,7 +1,7 @@
 use ast;
 use ast::Ast;
 use std::convert::TryInto;
-use std::ffi::CStr;
+use std::ffi::{CStr, CString};
 use std::fmt;
 use z3_sys::*;
 use {Context, FuncDecl, Sort, Symbol};
@@ -37,6 +37,34 @@ impl<'ctx> FuncDecl<'ctx> {
         }
     }
 
+    /// Create a fresh function declaration, whose name starts with `prefix`
+    /// and is guaranteed not to clash with any other declaration.
+    pub fn fresh(
+        ctx: &'ctx Context,
+        prefix: &str,
+        domain: &[&Sort<'ctx>],
+        range: &Sort<'ctx>,
+    ) -> Self {
+        assert!(domain.iter().all(|s| s.ctx.z3_ctx == ctx.z3_ctx));
+        assert_eq!(ctx.z3_ctx, range.ctx.z3_ctx);
+
+        let prefix = CString::new(prefix).unwrap();
+        let domain: Vec<_> = domain.iter().map(|s| s.z3_sort).collect();
+
+        unsafe {
+            Self::wrap(
+                ctx,
+                Z3_mk_fresh_func_decl(
+                    ctx.z3_ctx,
+                    prefix.as_ptr(),
+                    domain.len().try_into().unwrap(),
+                    domain.as_ptr(),
+                    range.z3_sort,
+                ),
+            )
+        }
+    }
+
     /// Return the number of arguments of a function declaration.
     ///
     /// If the function declaration is a constant, then the arity is `0`.
@@ -59,8 +87,22 @@ impl<'ctx> FuncDecl<'ctx> {
     /// Create a constant (if `args` has length 0) or function application (otherwise).
     ///
     /// Note that `args` should have the types corresponding to the `domain` of the `FuncDecl`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if this is an uninterpreted function and the number of `args`
+    /// differs from its arity. Built-in declarations such as `and` or `+` may
+    /// be associative and are not checked.
     pub fn apply(&self, args: &[&dyn ast::Ast<'ctx>]) -> ast::Dynamic<'ctx> {
         assert!(args.iter().all(|s| s.get_ctx().z3_ctx == self.ctx.z3_ctx));
+        if self.kind() == DeclKind::UNINTERPRETED {
+            assert_eq!(
+                self.arity(),
+                args.len(),
+                "wrong number of arguments applied to {}",
+                self.name()
+            );
+        }
 
         let args: Vec<_> = args.iter().map(|a| a.get_z3_ast()).collect();
 
@@ -106,6 +148,21 @@ impl<'ctx> FuncDecl<'ctx> {
         }
     }
 
+    /// Return the sort of the `i`-th parameter of the given declaration.
+    ///
+    /// Return `None` if `i` is not less than the arity of the declaration.
+    pub fn domain(&self, i: usize) -> Option<Sort<'ctx>> {
+        if i >= self.arity() {
+            return None;
+        }
+        unsafe {
+            Some(Sort::wrap(
+                self.ctx,
+                Z3_get_domain(self.ctx.z3_ctx, self.z3_func_decl, i as u32),
+            ))
+        }
+    }
+
     /// Return the range of the given declaration.
     ///
     /// If d is a constant (i.e. has zero arguments), then this function returns the sort of
@@ -0,0 +1,57 @@
+use ast::{Ast, Dynamic};
+use std::fmt;
+use z3_sys::*;
+use {Context, FuncEntry};
+
+impl<'ctx> FuncEntry<'ctx> {
+    pub(crate) unsafe fn wrap(ctx: &'ctx Context, z3_func_entry: Z3_func_entry) -> Self {
+        Z3_func_entry_inc_ref(ctx.z3_ctx, z3_func_entry);
+        Self { ctx, z3_func_entry }
+    }
+
+    /// Return the value of the function at the point represented by this entry.
+    pub fn get_value(&self) -> Dynamic<'ctx> {
+        unsafe {
+            Dynamic::wrap(
+                self.ctx,
+                Z3_func_entry_get_value(self.ctx.z3_ctx, self.z3_func_entry),
+            )
+        }
+    }
+
+    /// Return the number of arguments of this entry.
+    pub fn get_num_args(&self) -> u32 {
+        unsafe { Z3_func_entry_get_num_args(self.ctx.z3_ctx, self.z3_func_entry) }
+    }
+
+    /// Return the arguments of this entry.
+    pub fn get_args(&self) -> Vec<Dynamic<'ctx>> {
+        (0..self.get_num_args())
+            .map(|i| unsafe {
+                Dynamic::wrap(
+                    self.ctx,
+                    Z3_func_entry_get_arg(self.ctx.z3_ctx, self.z3_func_entry, i),
+                )
+            })
+            .collect()
+    }
+}
+
+impl<'ctx> fmt::Display for FuncEntry<'ctx> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        let args: Vec<_> = self.get_args().iter().map(|a| a.to_string()).collect();
+        write!(f, "[{}] -> {}", args.join(", "), self.get_value())
+    }
+}
+
+impl<'ctx> fmt::Debug for FuncEntry<'ctx> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        <Self as fmt::Display>::fmt(self, f)
+    }
+}
+
+impl<'ctx> Drop for FuncEntry<'ctx> {
+    fn drop(&mut self) {
+        unsafe { Z3_func_entry_dec_ref(self.ctx.z3_ctx, self.z3_func_entry) };
+    }
+}
@@ -0,0 +1,77 @@
+use ast::{Ast, Dynamic};
+use std::fmt;
+use z3_sys::*;
+use {Context, FuncEntry, FuncInterp};
+
+impl<'ctx> FuncInterp<'ctx> {
+    pub(crate) unsafe fn wrap(ctx: &'ctx Context, z3_func_interp: Z3_func_interp) -> Self {
+        Z3_func_interp_inc_ref(ctx.z3_ctx, z3_func_interp);
+        Self {
+            ctx,
+            z3_func_interp,
+        }
+    }
+
+    /// Return the number of arguments in the function interpretation.
+    pub fn get_arity(&self) -> usize {
+        unsafe { Z3_func_interp_get_arity(self.ctx.z3_ctx, self.z3_func_interp) as usize }
+    }
+
+    /// Return the number of entries in the function interpretation.
+    ///
+    /// A function interpretation is represented as a finite map and an
+    /// 'else' value. Each entry in the finite map represents the value of
+    /// the function given a set of arguments.
+    pub fn get_num_entries(&self) -> u32 {
+        unsafe { Z3_func_interp_get_num_entries(self.ctx.z3_ctx, self.z3_func_interp) }
+    }
+
+    /// Return the entries of the finite map of the function interpretation.
+    ///
+    /// # See also:
+    ///
+    /// - [`FuncInterp::get_else()`]
+    pub fn get_entries(&self) -> Vec<FuncEntry<'ctx>> {
+        (0..self.get_num_entries())
+            .map(|i| unsafe {
+                FuncEntry::wrap(
+                    self.ctx,
+                    Z3_func_interp_get_entry(self.ctx.z3_ctx, self.z3_func_interp, i),
+                )
+            })
+            .collect()
+    }
+
+    /// Return the 'else' value of the function interpretation, i.e. the
+    /// value of the function for all arguments not covered by the entries.
+    pub fn get_else(&self) -> Dynamic<'ctx> {
+        unsafe {
+            Dynamic::wrap(
+                self.ctx,
+                Z3_func_interp_get_else(self.ctx.z3_ctx, self.z3_func_interp),
+            )
+        }
+    }
+}
+
+impl<'ctx> fmt::Display for FuncInterp<'ctx> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        write!(f, "[")?;
+        for entry in self.get_entries() {
+            write!(f, "{}, ", entry)?;
+        }
+        write!(f, "else -> {}]", self.get_else())
+    }
+}
+
+impl<'ctx> fmt::Debug for FuncInterp<'ctx> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        <Self as fmt::Display>::fmt(self, f)
+    }
+}
+
+impl<'ctx> Drop for FuncInterp<'ctx> {
+    fn drop(&mut self) {
+        unsafe { Z3_func_interp_dec_ref(self.ctx.z3_ctx, self.z3_func_interp) };
+    }
+}
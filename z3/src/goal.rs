@@ -7,13 +7,11 @@ use crate::ast;
 use crate::ast::Ast;
 use Context;
 use Goal;
+use Model;
 
 impl<'ctx> Clone for Goal<'ctx> {
     fn clone(&self) -> Self {
-        Self {
-            ctx: self.ctx,
-            z3_goal: self.z3_goal,
-        }
+        unsafe { Self::wrap(self.ctx, self.z3_goal) }
     }
 }
 
@@ -77,6 +75,25 @@ impl<'ctx> Goal<'ctx> {
         }
     }
 
+    /// Convert a model of the formulas of this goal into a model of the
+    /// original goal that this goal was derived from by applying tactics.
+    ///
+    /// Tactics may eliminate variables, so a model of a subgoal need not
+    /// assign them. The converted model does. The original goal must have
+    /// been created with model generation enabled.
+    ///
+    /// # See also:
+    ///
+    /// - [`ApplyResult::list_subgoals()`](crate::ApplyResult::list_subgoals)
+    pub fn convert_model(&self, model: &Model<'ctx>) -> Model<'ctx> {
+        unsafe {
+            Model::wrap(
+                self.ctx,
+                Z3_goal_convert_model(self.ctx.z3_ctx, self.z3_goal, model.z3_mdl),
+            )
+        }
+    }
+
     /// Return the "precision" of the given goal. Goals can be transformed using over and under approximations.
     pub fn get_precision(&self) -> GoalPrec {
         unsafe { Z3_goal_precision(self.ctx.z3_ctx, self.z3_goal) }
@@ -95,6 +112,20 @@ impl<'ctx> Goal<'ctx> {
         })
     }
 
+    /// Return the formula at position `idx` in the given goal, or `None` if
+    /// `idx` is out of bounds.
+    pub fn get_formula(&self, idx: u32) -> Option<ast::Dynamic<'ctx>> {
+        if idx >= self.get_size() {
+            return None;
+        }
+        unsafe {
+            Some(ast::Dynamic::wrap(
+                self.ctx,
+                Z3_goal_formula(self.ctx.z3_ctx, self.z3_goal, idx),
+            ))
+        }
+    }
+
     /// Return a vector of the formulas from the given goal.
     pub fn get_formulas<T>(&self) -> Vec<T>
     where
@@ -15,13 +15,17 @@ extern crate num;
 
 use std::ffi::CString;
 use z3_sys::*;
-pub use z3_sys::{AstKind, GoalPrec, SortKind};
+pub use z3_sys::{AstKind, ErrorCode, GoalPrec, SortKind};
 
 pub mod ast;
+mod ast_map;
+mod ast_vector;
 mod config;
 mod context;
 pub mod datatype_builder;
 mod func_decl;
+mod func_entry;
+mod func_interp;
 mod goal;
 mod model;
 mod ops;
@@ -35,8 +39,12 @@ mod sort;
 mod statistics;
 mod symbol;
 mod tactic;
+mod trace;
 
+pub use model::{ModelIter, ModelSummary};
+pub use params::{get_global_param, reset_all_global_params, set_global_param};
 pub use statistics::{StatisticsEntry, StatisticsValue};
+pub use trace::{close_log, disable_trace, enable_trace, open_log};
 
 /// Configuration used to initialize [logical contexts](Context).
 ///
@@ -87,7 +95,7 @@ pub struct ContextHandle<'ctx> {
 }
 
 /// Symbols are used to name several term and type constructors.
-#[derive(PartialEq, Eq, Clone, Debug)]
+#[derive(PartialEq, Eq, Hash, Clone, Debug)]
 pub enum Symbol {
     Int(u32),
     String(String),
@@ -109,6 +117,25 @@ pub struct SortDiffers<'ctx> {
     right: Sort<'ctx>,
 }
 
+/// A struct to represent when an ast does not have the sort of the
+/// requested Rust type.
+#[derive(Debug)]
+pub struct SortMismatch<'ctx> {
+    expected: &'static str,
+    actual: Sort<'ctx>,
+}
+
+/// An error reported by Z3 through its error code.
+///
+/// # See also:
+///
+/// - [`Context::check_error()`]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Z3Error {
+    code: ErrorCode,
+    message: String,
+}
+
 /// A struct to represent when an ast is not a function application.
 #[derive(Debug)]
 pub struct IsNotApp {
@@ -122,6 +149,9 @@ pub struct IsNotApp {
 pub struct Solver<'ctx> {
     ctx: &'ctx Context,
     z3_slv: Z3_solver,
+    // The logic passed to `Solver::new_for_logic()`, which Z3 cannot
+    // report back but `Solver::to_smt2()` needs.
+    logic: Option<Symbol>,
 }
 
 /// Model for the constraints inserted into the logical context.
@@ -133,6 +163,32 @@ pub struct Model<'ctx> {
     z3_mdl: Z3_model,
 }
 
+/// Stores the interpretation of a function in a Z3 model.
+///
+/// # See also:
+///
+/// - [`Model::get_func_interp()`]
+//
+// Note for in-crate users: Never construct a `FuncInterp` directly; only use
+// `FuncInterp::wrap()` which handles Z3 refcounting properly.
+pub struct FuncInterp<'ctx> {
+    ctx: &'ctx Context,
+    z3_func_interp: Z3_func_interp,
+}
+
+/// Stores the value of the interpretation of a function at a particular point.
+///
+/// # See also:
+///
+/// - [`FuncInterp::get_entries()`]
+//
+// Note for in-crate users: Never construct a `FuncEntry` directly; only use
+// `FuncEntry::wrap()` which handles Z3 refcounting properly.
+pub struct FuncEntry<'ctx> {
+    ctx: &'ctx Context,
+    z3_func_entry: Z3_func_entry,
+}
+
 /// Context for solving optimization queries.
 //
 // Note for in-crate users: Never construct an `Optimize` directly; only use
@@ -319,3 +375,15 @@ pub struct Statistics<'ctx> {
     ctx: &'ctx Context,
     z3_stats: Z3_stats,
 }
+
+/// A vector of [`Ast`s](ast::Ast) of possibly different sorts.
+pub struct AstVector<'ctx> {
+    ctx: &'ctx Context,
+    z3_ast_vector: Z3_ast_vector,
+}
+
+/// A mapping from [`Ast`s](ast::Ast) to [`Ast`s](ast::Ast).
+pub struct AstMap<'ctx> {
+    ctx: &'ctx Context,
+    z3_ast_map: Z3_ast_map,
+}
@@ -1,14 +1,16 @@
-use ast::Ast;
+use ast::{Ast, Dynamic};
+use std::any;
+use std::convert::TryFrom;
 use std::ffi::CStr;
 use std::fmt;
 use z3_sys::*;
 use Optimize;
 use Solver;
-use {Context, FuncDecl};
-use {Model, Sort};
+use {AstVector, Context, FuncDecl, FuncInterp};
+use {Model, Sort, SortMismatch};
 
 impl<'ctx> Model<'ctx> {
-    unsafe fn wrap(ctx: &'ctx Context, z3_mdl: Z3_model) -> Model<'ctx> {
+    pub(crate) unsafe fn wrap(ctx: &'ctx Context, z3_mdl: Z3_model) -> Model<'ctx> {
         Z3_model_inc_ref(ctx.z3_ctx, z3_mdl);
         Model { ctx, z3_mdl }
     }
@@ -68,6 +70,114 @@ impl<'ctx> Model<'ctx> {
         }
     }
 
+    /// Evaluate `ast` in the model like [`Model::eval()`], but return
+    /// `ast` simplified instead of `None` if the evaluation fails.
+    ///
+    /// Without `model_completion`, subterms the model does not assign are
+    /// left in the result, so the result need not be a value.
+    pub fn eval_or_simplify<T>(&self, ast: &T, model_completion: bool) -> T
+    where
+        T: Ast<'ctx>,
+    {
+        self.eval(ast, model_completion)
+            .unwrap_or_else(|| ast.simplify())
+    }
+
+    /// Evaluate each of the given `asts` in the model.
+    ///
+    /// The results are returned positionally: the `i`-th element of the
+    /// returned vector is the result of evaluating `asts[i]`, and is `None`
+    /// if that evaluation failed.
+    ///
+    /// # See also:
+    ///
+    /// - [`Model::eval()`]
+    pub fn eval_many<T>(&self, asts: &[T], model_completion: bool) -> Vec<Option<T>>
+    where
+        T: Ast<'ctx>,
+    {
+        asts.iter()
+            .map(|ast| self.eval(ast, model_completion))
+            .collect()
+    }
+
+    /// Evaluate `ast` in the model and return the result as a string.
+    ///
+    /// Return `None` if the evaluation failed, e.g. because `ast` is not
+    /// assigned by the model and `model_completion` is false.
+    ///
+    /// # See also:
+    ///
+    /// - [`Model::eval()`]
+    pub fn eval_to_string<T>(&self, ast: &T, model_completion: bool) -> Option<String>
+    where
+        T: Ast<'ctx>,
+    {
+        let res = self.eval(ast, model_completion)?;
+        let p = unsafe { Z3_ast_to_string(self.ctx.z3_ctx, res.get_z3_ast()) };
+        if p.is_null() {
+            return None;
+        }
+        unsafe { CStr::from_ptr(p) }
+            .to_str()
+            .ok()
+            .map(|s| s.to_owned())
+    }
+
+    /// Evaluate `ast` in the model and return its value as an `i64`.
+    ///
+    /// Return `None` if the evaluation failed, if the result is not a
+    /// numeral, or if the numeral does not fit in an `i64`.
+    ///
+    /// # See also:
+    ///
+    /// - [`Model::eval()`]
+    /// - [`Model::eval_f64()`]
+    pub fn eval_i64<T>(&self, ast: &T, model_completion: bool) -> Option<i64>
+    where
+        T: Ast<'ctx>,
+    {
+        let res = self.eval(ast, model_completion)?;
+        let mut tmp: i64 = 0;
+        unsafe {
+            if Z3_is_numeral_ast(self.ctx.z3_ctx, res.get_z3_ast())
+                && Z3_get_numeral_int64(self.ctx.z3_ctx, res.get_z3_ast(), &mut tmp)
+            {
+                Some(tmp)
+            } else {
+                None
+            }
+        }
+    }
+
+    /// Evaluate `ast` in the model and return its value as an `f64`.
+    ///
+    /// Return `None` if the evaluation failed, if the result is not a
+    /// numeral, or if the numeral is too large to be represented as a
+    /// finite `f64`.
+    ///
+    /// # See also:
+    ///
+    /// - [`Model::eval()`]
+    /// - [`Model::eval_i64()`]
+    pub fn eval_f64<T>(&self, ast: &T, model_completion: bool) -> Option<f64>
+    where
+        T: Ast<'ctx>,
+    {
+        let res = self.eval(ast, model_completion)?;
+        unsafe {
+            if !Z3_is_numeral_ast(self.ctx.z3_ctx, res.get_z3_ast()) {
+                return None;
+            }
+            let val = Z3_get_numeral_double(self.ctx.z3_ctx, res.get_z3_ast());
+            if val.is_finite() {
+                Some(val)
+            } else {
+                None
+            }
+        }
+    }
+
     /// Returns the number of constants assigned by the given model.
     pub fn get_num_consts(&self) -> u32 {
         unsafe { Z3_model_get_num_consts(self.ctx.z3_ctx, self.z3_mdl) }
@@ -88,6 +198,68 @@ impl<'ctx> Model<'ctx> {
         }
     }
 
+    /// Iterate over the constants assigned by the given model, yielding
+    /// each constant's declaration together with its interpretation.
+    ///
+    /// # Examples
+    /// ```
+    /// # use z3::{ast, Config, Context, SatResult, Solver};
+    /// # use z3::ast::Ast;
+    /// # let cfg = Config::new();
+    /// # let ctx = Context::new(&cfg);
+    /// # let solver = Solver::new(&ctx);
+    /// let x = ast::Int::new_const(&ctx, "x");
+    /// solver.assert(&x._eq(&ast::Int::from_i64(&ctx, 3)));
+    /// assert_eq!(solver.check(), SatResult::Sat);
+    ///
+    /// let model = solver.get_model().unwrap();
+    /// for (decl, value) in model.iter() {
+    ///     println!("{} = {}", decl.name(), value);
+    /// }
+    /// ```
+    pub fn iter<'a>(&'a self) -> ModelIter<'a, 'ctx> {
+        ModelIter {
+            model: self,
+            idx: 0,
+            len: self.get_num_consts(),
+        }
+    }
+
+    /// Returns the number of function interpretations in the given model.
+    ///
+    /// A function interpretation is represented as a finite map and an 'else' value.
+    pub fn get_num_funcs(&self) -> u32 {
+        unsafe { Z3_model_get_num_funcs(self.ctx.z3_ctx, self.z3_mdl) }
+    }
+
+    /// Return the declaration of the index-th function in the given model.
+    /// Return None if the index is invalid.
+    ///
+    /// # See also:
+    ///
+    /// - [`Model::get_func_interp()`]
+    pub fn get_func_decl(&self, index: u32) -> Option<FuncDecl<'ctx>> {
+        if index >= self.get_num_funcs() {
+            None
+        } else {
+            unsafe {
+                Some(FuncDecl::wrap(
+                    self.ctx,
+                    Z3_model_get_func_decl(self.ctx.z3_ctx, self.z3_mdl, index),
+                ))
+            }
+        }
+    }
+
+    /// Return `true` if the given model assigns an interpretation to
+    /// the constant or function `func_decl`.
+    ///
+    /// Unlike [`Model::get_const_interp()`], this does not require knowing
+    /// the sort of the interpretation in advance.
+    pub fn has_interp(&self, func_decl: &FuncDecl) -> bool {
+        unsafe { Z3_model_has_interp(self.ctx.z3_ctx, self.z3_mdl, func_decl.z3_func_decl) }
+    }
+
     /// Return the interpretation (i.e., assignment) of constant associated to `func_decl` in the given model.
     ///
     /// Return None if the model does not assign an interpretation to the constant associated with `func_decl`. That
@@ -116,6 +288,39 @@ impl<'ctx> Model<'ctx> {
         }
     }
 
+    /// Return the interpretation (i.e., assignment) of constant associated to `func_decl`
+    /// in the given model.
+    ///
+    /// Return `Ok(None)` if the model does not assign an interpretation to the constant
+    /// associated with `func_decl`, and `Err` if the interpretation is not of the sort
+    /// expected by `T`.
+    ///
+    /// # See also:
+    ///
+    /// - [`Model::get_const_interp()`]
+    pub fn try_get_const_interp<T>(
+        &self,
+        func_decl: &FuncDecl,
+    ) -> Result<Option<T>, SortMismatch<'ctx>>
+    where
+        T: Ast<'ctx> + TryFrom<Dynamic<'ctx>>,
+    {
+        let res_ast = unsafe {
+            Z3_model_get_const_interp(self.ctx.z3_ctx, self.z3_mdl, func_decl.z3_func_decl)
+        };
+
+        if res_ast.is_null() {
+            return Ok(None);
+        }
+
+        let res = unsafe { Dynamic::wrap(self.ctx, res_ast) };
+        let actual = res.get_sort();
+        match T::try_from(res) {
+            Ok(res) => Ok(Some(res)),
+            Err(_) => Err(SortMismatch::new(any::type_name::<T>(), actual)),
+        }
+    }
+
     /// Return the interpretation (i.e., assignment) of constant associated to `func_decl`
     /// in the given model.
     ///
@@ -145,6 +350,189 @@ impl<'ctx> Model<'ctx> {
             Some(unsafe { T::wrap(self.ctx, res_ast) })
         }
     }
+
+    /// Returns the number of constants, functions and uninterpreted sorts
+    /// interpreted by this model.
+    pub fn summary(&self) -> ModelSummary {
+        ModelSummary {
+            num_consts: self.get_num_consts(),
+            num_funcs: self.get_num_funcs(),
+            num_sorts: self.get_num_sorts(),
+        }
+    }
+
+    /// Returns the number of uninterpreted sorts that the given model
+    /// assigns an interpretation to.
+    pub fn get_num_sorts(&self) -> u32 {
+        unsafe { Z3_model_get_num_sorts(self.ctx.z3_ctx, self.z3_mdl) }
+    }
+
+    /// Return the index-th uninterpreted sort in the given model.
+    /// Return None if the index is invalid.
+    ///
+    /// # See also:
+    ///
+    /// - [`Model::get_sort_universe()`]
+    pub fn get_sort(&self, index: u32) -> Option<Sort<'ctx>> {
+        if index >= self.get_num_sorts() {
+            None
+        } else {
+            unsafe {
+                Some(Sort::wrap(
+                    self.ctx,
+                    Z3_model_get_sort(self.ctx.z3_ctx, self.z3_mdl, index),
+                ))
+            }
+        }
+    }
+
+    /// Return the finite set of distinct values that represent the
+    /// interpretation of the uninterpreted `sort` in the given model.
+    ///
+    /// Return an empty vector if the model does not assign a universe to `sort`.
+    pub fn get_sort_universe(&self, sort: &Sort<'ctx>) -> Vec<Dynamic<'ctx>> {
+        let z3_universe =
+            unsafe { Z3_model_get_sort_universe(self.ctx.z3_ctx, self.z3_mdl, sort.z3_sort) };
+        if z3_universe.is_null() {
+            return vec![];
+        }
+
+        let universe = unsafe { AstVector::wrap(self.ctx, z3_universe) };
+        universe.iter().collect()
+    }
+
+    /// Return the interpretation of the function `func_decl` in the given model.
+    ///
+    /// Return None if the model does not assign an interpretation to `func_decl`.
+    pub fn get_func_interp(&self, func_decl: &FuncDecl) -> Option<FuncInterp<'ctx>> {
+        let z3_func_interp = unsafe {
+            Z3_model_get_func_interp(self.ctx.z3_ctx, self.z3_mdl, func_decl.z3_func_decl)
+        };
+
+        if z3_func_interp.is_null() {
+            None
+        } else {
+            Some(unsafe { FuncInterp::wrap(self.ctx, z3_func_interp) })
+        }
+    }
+
+    /// Serialize the model as a sequence of SMT-LIB2 `define-fun` commands,
+    /// one per function and constant, which can be parsed back by e.g.
+    /// [`Solver::from_string()`].
+    ///
+    /// This is Z3's own printer in SMT-LIB2 compliant mode, whereas the
+    /// [`Display`](fmt::Display) implementation uses Z3's default model
+    /// format. Z3 prints auxiliary functions such as `k!0` after the
+    /// definitions that use them (e.g. `(_ as-array k!0)`), so models with
+    /// such arrays do not parse back as-is.
+    ///
+    /// # See also:
+    ///
+    /// - [`Model::iter()`]
+    /// - [`Model::get_func_interp()`]
+    pub fn to_smt2(&self) -> String {
+        unsafe {
+            Z3_set_ast_print_mode(self.ctx.z3_ctx, AstPrintMode::SmtLib2Compliant);
+            let p = Z3_model_to_string(self.ctx.z3_ctx, self.z3_mdl);
+            let s = if p.is_null() {
+                String::new()
+            } else {
+                CStr::from_ptr(p).to_string_lossy().into_owned()
+            };
+            // The crate never changes the print mode, so this is the one
+            // every context starts with.
+            Z3_set_ast_print_mode(self.ctx.z3_ctx, AstPrintMode::SmtLibFull);
+            s
+        }
+    }
+}
+
+/// The number of entries of each kind in a [`Model`].
+///
+/// # See also:
+///
+/// - [`Model::summary()`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ModelSummary {
+    /// See [`Model::get_num_consts()`].
+    pub num_consts: u32,
+    /// See [`Model::get_num_funcs()`].
+    pub num_funcs: u32,
+    /// See [`Model::get_num_sorts()`].
+    pub num_sorts: u32,
+}
+
+/// Iterator over the constant assignments of a [`Model`].
+///
+/// # See also:
+///
+/// - [`Model::iter()`]
+#[derive(Debug)]
+pub struct ModelIter<'a, 'ctx> {
+    model: &'a Model<'ctx>,
+    idx: u32,
+    len: u32,
+}
+
+impl<'a, 'ctx> Iterator for ModelIter<'a, 'ctx> {
+    type Item = (FuncDecl<'ctx>, Dynamic<'ctx>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.idx >= self.len {
+            return None;
+        }
+        let ctx = self.model.ctx;
+        let z3_mdl = self.model.z3_mdl;
+        let item = unsafe {
+            let z3_func_decl = Z3_model_get_const_decl(ctx.z3_ctx, z3_mdl, self.idx);
+            let z3_interp = Z3_model_get_const_interp(ctx.z3_ctx, z3_mdl, z3_func_decl);
+            (
+                FuncDecl::wrap(ctx, z3_func_decl),
+                Dynamic::wrap(ctx, z3_interp),
+            )
+        };
+        self.idx += 1;
+        Some(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = (self.len - self.idx) as usize;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<'a, 'ctx> ExactSizeIterator for ModelIter<'a, 'ctx> {}
+
+impl<'ctx> SortMismatch<'ctx> {
+    pub fn new(expected: &'static str, actual: Sort<'ctx>) -> Self {
+        Self { expected, actual }
+    }
+
+    /// Name of the Rust type that was requested.
+    pub fn expected(&self) -> &'static str {
+        self.expected
+    }
+
+    /// Sort of the ast that was found.
+    pub fn actual(&self) -> &Sort<'ctx> {
+        &self.actual
+    }
+}
+
+impl<'ctx> fmt::Display for SortMismatch<'ctx> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        write!(
+            f,
+            "Sort does not match.  Expected {}, found an ast of sort {}",
+            self.expected, self.actual
+        )
+    }
+}
+
+impl<'ctx> Clone for Model<'ctx> {
+    fn clone(&self) -> Self {
+        unsafe { Self::wrap(self.ctx, self.z3_mdl) }
+    }
 }
 
 impl<'ctx> fmt::Display for Model<'ctx> {
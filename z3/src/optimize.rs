@@ -3,6 +3,7 @@ use std::convert::TryInto;
 use std::ffi::{CStr, CString};
 use std::fmt;
 use z3_sys::*;
+use AstVector;
 use Context;
 use Model;
 use Optimize;
@@ -32,6 +33,22 @@ impl<'ctx> Optimize<'ctx> {
         self.ctx
     }
 
+    /// Parse an SMT-LIB2 string with assertions, soft constraints and
+    /// optimization objectives, and add them to the optimization context.
+    ///
+    /// The string can be produced from another `Optimize` with its
+    /// `Display` implementation.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `source_string` contains a NUL byte.
+    pub fn from_string<T: Into<Vec<u8>>>(&self, source_string: T) {
+        let source_cstring = CString::new(source_string).unwrap();
+        unsafe {
+            Z3_optimize_from_string(self.ctx.z3_ctx, self.z3_opt, source_cstring.as_ptr());
+        }
+    }
+
     /// Assert hard constraint to the optimization context.
     ///
     /// # See also:
@@ -47,12 +64,20 @@ impl<'ctx> Optimize<'ctx> {
     /// Weight is a positive, rational penalty for violating the constraint.
     /// Group is an optional identifier to group soft constraints.
     ///
+    /// Returns the index of the objective formed by the soft constraints of
+    /// `group`.
+    ///
     /// # See also:
     ///
     /// - [`Optimize::assert()`]
     /// - [`Optimize::maximize()`]
     /// - [`Optimize::minimize()`]
-    pub fn assert_soft(&self, ast: &impl Ast<'ctx>, weight: impl Weight, group: Option<Symbol>) {
+    pub fn assert_soft(
+        &self,
+        ast: &impl Ast<'ctx>,
+        weight: impl Weight,
+        group: Option<Symbol>,
+    ) -> u32 {
         let weight_string = weight.to_string();
         let weight_cstring = CString::new(weight_string).unwrap();
         let group = group
@@ -66,27 +91,33 @@ impl<'ctx> Optimize<'ctx> {
                 weight_cstring.as_ptr(),
                 group,
             )
-        };
+        }
     }
 
     /// Add a maximization constraint.
     ///
+    /// Returns the index of the objective, which identifies it in later
+    /// queries about its optimal bounds.
+    ///
     /// # See also:
     ///
     /// - [`Optimize::assert()`]
     /// - [`Optimize::minimize()`]
-    pub fn maximize(&self, ast: &impl Ast<'ctx>) {
-        unsafe { Z3_optimize_maximize(self.ctx.z3_ctx, self.z3_opt, ast.get_z3_ast()) };
+    pub fn maximize(&self, ast: &impl Ast<'ctx>) -> u32 {
+        unsafe { Z3_optimize_maximize(self.ctx.z3_ctx, self.z3_opt, ast.get_z3_ast()) }
     }
 
     /// Add a minimization constraint.
     ///
+    /// Returns the index of the objective, which identifies it in later
+    /// queries about its optimal bounds.
+    ///
     /// # See also:
     ///
     /// - [`Optimize::assert()`]
     /// - [`Optimize::maximize()`]
-    pub fn minimize(&self, ast: &impl Ast<'ctx>) {
-        unsafe { Z3_optimize_minimize(self.ctx.z3_ctx, self.z3_opt, ast.get_z3_ast()) };
+    pub fn minimize(&self, ast: &impl Ast<'ctx>) -> u32 {
+        unsafe { Z3_optimize_minimize(self.ctx.z3_ctx, self.z3_opt, ast.get_z3_ast()) }
     }
 
     /// Create a backtracking point.
@@ -151,21 +182,68 @@ impl<'ctx> Optimize<'ctx> {
     ///
     /// This contains maximize/minimize objectives and grouped soft constraints.
     pub fn get_objectives(&self) -> Vec<Dynamic<'ctx>> {
-        let (z3_objectives, len) = unsafe {
-            let objectives = Z3_optimize_get_objectives(self.ctx.z3_ctx, self.z3_opt);
-            let len = Z3_ast_vector_size(self.ctx.z3_ctx, objectives);
-            (objectives, len)
+        let objectives = unsafe {
+            AstVector::wrap(
+                self.ctx,
+                Z3_optimize_get_objectives(self.ctx.z3_ctx, self.z3_opt),
+            )
         };
+        objectives.iter().collect()
+    }
 
-        let mut objectives = Vec::with_capacity(len as usize);
+    /// Return the hard constraints asserted into the optimization context,
+    /// in order.
+    ///
+    /// # See also:
+    ///
+    /// - [`Optimize::assert()`]
+    pub fn get_assertions(&self) -> Vec<Bool<'ctx>> {
+        let assertions = unsafe {
+            AstVector::wrap(
+                self.ctx,
+                Z3_optimize_get_assertions(self.ctx.z3_ctx, self.z3_opt),
+            )
+        };
+        assertions
+            .iter()
+            .map(|assertion| assertion.as_bool().unwrap())
+            .collect()
+    }
 
-        for i in 0..len {
-            let elem = unsafe { Z3_ast_vector_get(self.ctx.z3_ctx, z3_objectives, i) };
-            let elem = unsafe { Dynamic::wrap(self.ctx, elem) };
-            objectives.push(elem);
+    /// Retrieve the lower bound of the objective with index `idx` after
+    /// the last [`Optimize::check()`].
+    ///
+    /// `idx` is the value returned by [`Optimize::maximize()`],
+    /// [`Optimize::minimize()`] or [`Optimize::assert_soft()`].
+    ///
+    /// # See also:
+    ///
+    /// - [`Optimize::get_upper()`]
+    pub fn get_lower(&self, idx: u32) -> Dynamic<'ctx> {
+        unsafe {
+            Dynamic::wrap(
+                self.ctx,
+                Z3_optimize_get_lower(self.ctx.z3_ctx, self.z3_opt, idx),
+            )
         }
+    }
 
-        objectives
+    /// Retrieve the upper bound of the objective with index `idx` after
+    /// the last [`Optimize::check()`].
+    ///
+    /// `idx` is the value returned by [`Optimize::maximize()`],
+    /// [`Optimize::minimize()`] or [`Optimize::assert_soft()`].
+    ///
+    /// # See also:
+    ///
+    /// - [`Optimize::get_lower()`]
+    pub fn get_upper(&self, idx: u32) -> Dynamic<'ctx> {
+        unsafe {
+            Dynamic::wrap(
+                self.ctx,
+                Z3_optimize_get_upper(self.ctx.z3_ctx, self.z3_opt, idx),
+            )
+        }
     }
 
     /// Retrieve a string that describes the last status returned by [`Optimize::check()`].
@@ -1,5 +1,6 @@
-use std::ffi::CStr;
+use std::ffi::{CStr, CString};
 use std::fmt;
+use std::ptr;
 use z3_sys::*;
 use Context;
 use Params;
@@ -60,6 +61,54 @@ impl<'ctx> Params<'ctx> {
     }
 }
 
+/// Get a global (or module) parameter.
+///
+/// Returns `None` if the parameter does not exist. Parameters that have not
+/// been set return their default value.
+///
+/// # See also
+///
+/// - [`set_global_param()`]
+/// - [`reset_all_global_params()`]
+pub fn get_global_param(k: &str) -> Option<String> {
+    let ks = CString::new(k).unwrap();
+    let mut value: Z3_string = ptr::null();
+    unsafe {
+        if Z3_global_param_get(ks.as_ptr(), &mut value) && !value.is_null() {
+            CStr::from_ptr(value).to_str().ok().map(String::from)
+        } else {
+            None
+        }
+    }
+}
+
+/// Set a global (or module) parameter. This setting is shared by all Z3
+/// contexts, and only affects objects created after the call.
+///
+/// For example, `set_global_param("rewriter.flat", "false")` sets the
+/// parameter `flat` of the module `rewriter`.
+///
+/// # See also
+///
+/// - [`get_global_param()`]
+/// - [`reset_all_global_params()`]
+pub fn set_global_param(k: &str, v: &str) {
+    let ks = CString::new(k).unwrap();
+    let vs = CString::new(v).unwrap();
+    unsafe { Z3_global_param_set(ks.as_ptr(), vs.as_ptr()) };
+}
+
+/// Restore the value of all global (and module) parameters. This does not
+/// affect already created objects such as solvers and tactics.
+///
+/// # See also
+///
+/// - [`get_global_param()`]
+/// - [`set_global_param()`]
+pub fn reset_all_global_params() {
+    unsafe { Z3_global_param_reset_all() };
+}
+
 impl<'ctx> fmt::Display for Params<'ctx> {
     fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
         let p = unsafe { Z3_params_to_string(self.ctx.z3_ctx, self.z3_params) };
@@ -1,8 +1,10 @@
 use ast;
 use ast::Ast;
-use std::ffi::CStr;
+use std::convert::TryFrom;
+use std::ffi::{CStr, CString};
 use std::fmt;
 use z3_sys::*;
+use AstVector;
 use Context;
 use Model;
 use Params;
@@ -14,7 +16,11 @@ use Symbol;
 impl<'ctx> Solver<'ctx> {
     pub(crate) unsafe fn wrap(ctx: &'ctx Context, z3_slv: Z3_solver) -> Solver<'ctx> {
         Z3_solver_inc_ref(ctx.z3_ctx, z3_slv);
-        Solver { ctx, z3_slv }
+        Solver {
+            ctx,
+            z3_slv,
+            logic: None,
+        }
     }
 
     /// Create a new solver. This solver is a "combined solver"
@@ -55,23 +61,32 @@ impl<'ctx> Solver<'ctx> {
     /// Create a new solver customized for the given logic.
     /// It returns `None` if the logic is unknown or unsupported.
     pub fn new_for_logic<S: Into<Symbol>>(ctx: &'ctx Context, logic: S) -> Option<Solver<'ctx>> {
+        let logic = logic.into();
         unsafe {
-            let s = Z3_mk_solver_for_logic(ctx.z3_ctx, logic.into().as_z3_symbol(ctx));
+            let s = Z3_mk_solver_for_logic(ctx.z3_ctx, logic.as_z3_symbol(ctx));
             if s.is_null() {
                 None
             } else {
-                Some(Self::wrap(ctx, s))
+                let mut solver = Self::wrap(ctx, s);
+                solver.logic = Some(logic);
+                Some(solver)
             }
         }
     }
 
+    /// Copy the solver, including its assertions, to the context `dest`.
+    ///
+    /// The resulting solver is independent of `self` and may outlive the
+    /// source context.
     pub fn translate<'dest_ctx>(&self, dest: &'dest_ctx Context) -> Solver<'dest_ctx> {
-        unsafe {
+        let mut solver = unsafe {
             Solver::wrap(
                 dest,
                 Z3_solver_translate(self.ctx.z3_ctx, self.z3_slv, dest.z3_ctx),
             )
-        }
+        };
+        solver.logic = self.logic.clone();
+        solver
     }
 
     /// Get this solver's context.
@@ -79,6 +94,65 @@ impl<'ctx> Solver<'ctx> {
         self.ctx
     }
 
+    /// Parse an SMT-LIB2 string with commands and add the resulting
+    /// assertions to the solver.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `source_string` contains a NUL byte.
+    pub fn from_string<T: Into<Vec<u8>>>(&self, source_string: T) {
+        let source_cstring = CString::new(source_string).unwrap();
+        unsafe {
+            Z3_solver_from_string(self.ctx.z3_ctx, self.z3_slv, source_cstring.as_ptr());
+        }
+    }
+
+    /// Serialize the assertions of the solver as an SMT-LIB2 benchmark,
+    /// including declarations and a final `(check-sat)`.
+    ///
+    /// If the solver was created with [`Solver::new_for_logic()`], the
+    /// benchmark starts with a matching `(set-logic ...)` command.
+    ///
+    /// # See also:
+    ///
+    /// - [`Context::from_smt2()`]
+    /// - [`Solver::from_string()`]
+    pub fn to_smt2(&self) -> String {
+        let assertions = self.get_assertions();
+        let (formula, assumptions) = match assertions.split_last() {
+            Some((last, rest)) => (last.clone(), rest),
+            None => (ast::Bool::from_bool(self.ctx, true), &[][..]),
+        };
+        let assumptions: Vec<Z3_ast> = assumptions.iter().map(|a| a.z3_ast).collect();
+
+        let name = CString::new("benchmark generated from rust API").unwrap();
+        let logic = CString::new(
+            self.logic
+                .as_ref()
+                .map(Symbol::to_string)
+                .unwrap_or_default(),
+        )
+        .unwrap();
+        let empty = CString::new("").unwrap();
+        let status = CString::new("unknown").unwrap();
+        let p = unsafe {
+            Z3_benchmark_to_smtlib_string(
+                self.ctx.z3_ctx,
+                name.as_ptr(),
+                logic.as_ptr(),
+                status.as_ptr(),
+                empty.as_ptr(),
+                assumptions.len() as u32,
+                assumptions.as_ptr(),
+                formula.z3_ast,
+            )
+        };
+        if p.is_null() {
+            return String::new();
+        }
+        unsafe { CStr::from_ptr(p) }.to_string_lossy().into_owned()
+    }
+
     /// Assert a constraint into the solver.
     ///
     /// The functions [`Solver::check()`] and [`Solver::check_assumptions()`]
@@ -112,6 +186,21 @@ impl<'ctx> Solver<'ctx> {
         unsafe { Z3_solver_assert_and_track(self.ctx.z3_ctx, self.z3_slv, ast.z3_ast, p.z3_ast) };
     }
 
+    /// Return the formulas asserted into the solver, in order.
+    ///
+    /// # See also:
+    ///
+    /// - [`Solver::assert()`]
+    pub fn get_assertions(&self) -> Vec<ast::Bool<'ctx>> {
+        let z3_assertions = unsafe { Z3_solver_get_assertions(self.ctx.z3_ctx, self.z3_slv) };
+        if z3_assertions.is_null() {
+            return vec![];
+        }
+
+        let assertions = unsafe { AstVector::wrap(self.ctx, z3_assertions) };
+        assertions.iter().map(|a| a.as_bool().unwrap()).collect()
+    }
+
     /// Remove all assertions from the solver.
     pub fn reset(&self) {
         unsafe { Z3_solver_reset(self.ctx.z3_ctx, self.z3_slv) };
@@ -136,6 +225,7 @@ impl<'ctx> Solver<'ctx> {
     ///
     /// - [`Config::set_model_generation()`](crate::Config::set_model_generation)
     /// - [`Config::set_proof_generation()`](crate::Config::set_proof_generation)
+    /// - [`Context::interrupt()`]
     /// - [`Solver::check_assumptions()`]
     ///
     /// [model construction is enabled]: crate::Config::set_model_generation
@@ -149,6 +239,25 @@ impl<'ctx> Solver<'ctx> {
         }
     }
 
+    /// Like [`Solver::check()`], but give up and return
+    /// [`SatResult::Unknown`] if no answer is found within `ms` milliseconds.
+    ///
+    /// Z3 cannot report a solver's current parameters, so the timeout is
+    /// not restored afterwards: the solver's `timeout` parameter is reset to
+    /// unlimited, Z3's default. This overrides any timeout set earlier
+    /// through [`Solver::set_params()`] or inherited from the context's
+    /// [`Config`](crate::Config).
+    pub fn check_timeout(&self, ms: u64) -> SatResult {
+        let mut params = Params::new(self.ctx);
+        params.set_u32("timeout", u32::try_from(ms).unwrap_or(u32::MAX));
+        self.set_params(&params);
+        let result = self.check();
+        let mut params = Params::new(self.ctx);
+        params.set_u32("timeout", u32::MAX);
+        self.set_params(&params);
+        result
+    }
+
     /// Check whether the assertions in the given solver and
     /// optional assumptions are consistent or not.
     ///
@@ -193,17 +302,82 @@ impl<'ctx> Solver<'ctx> {
             return vec![];
         }
 
-        let len = unsafe { Z3_ast_vector_size(self.ctx.z3_ctx, z3_unsat_core) };
-
-        let mut unsat_core = Vec::with_capacity(len as usize);
+        let unsat_core = unsafe { AstVector::wrap(self.ctx, z3_unsat_core) };
+        unsat_core.iter().map(|a| a.as_bool().unwrap()).collect()
+    }
 
-        for i in 0..len {
-            let elem = unsafe { Z3_ast_vector_get(self.ctx.z3_ctx, z3_unsat_core, i) };
-            let elem = unsafe { ast::Bool::wrap(self.ctx, elem) };
-            unsat_core.push(elem);
+    /// Retrieve consequences from the solver given a set of assumptions.
+    ///
+    /// Each consequence is an implication of the form
+    /// `(=> (and assumptions) l)`, where `l` is one of the `variables` or its
+    /// negation that is entailed by the assertions and `assumptions`.
+    /// `variables` for which no truth value is forced have no consequence.
+    ///
+    /// # See also:
+    ///
+    /// - [`Solver::check_assumptions()`]
+    pub fn get_consequences(
+        &self,
+        assumptions: &[ast::Bool<'ctx>],
+        variables: &[ast::Bool<'ctx>],
+    ) -> (SatResult, Vec<ast::Bool<'ctx>>) {
+        let mut z3_assumptions = AstVector::new(self.ctx);
+        for a in assumptions {
+            z3_assumptions.push(a);
+        }
+        let mut z3_variables = AstVector::new(self.ctx);
+        for v in variables {
+            z3_variables.push(v);
         }
+        let z3_consequences = AstVector::new(self.ctx);
 
-        unsat_core
+        let res = match unsafe {
+            Z3_solver_get_consequences(
+                self.ctx.z3_ctx,
+                self.z3_slv,
+                z3_assumptions.z3_ast_vector,
+                z3_variables.z3_ast_vector,
+                z3_consequences.z3_ast_vector,
+            )
+        } {
+            Z3_L_FALSE => SatResult::Unsat,
+            Z3_L_UNDEF => SatResult::Unknown,
+            Z3_L_TRUE => SatResult::Sat,
+            _ => unreachable!(),
+        };
+        let consequences = z3_consequences
+            .iter()
+            .map(|c| c.as_bool().unwrap())
+            .collect();
+        (res, consequences)
+    }
+
+    /// Extract the next cube from the solver, as a conjunction of literals.
+    ///
+    /// Repeated calls enumerate the cube space. A cube consisting of the
+    /// single literal `false` means the space is exhausted, and an empty
+    /// cube means the remaining problem could not be split any further, so
+    /// callers should stop after it as well.
+    ///
+    /// `vars` holds the variables that may be used for cubing. Its contents
+    /// are only read on the first call, and Z3 replaces them with the
+    /// variables affected by the last literal of the cube, which may be used
+    /// for further cubing with a different solver. Passing `u32::MAX` as
+    /// `backtrack_level` lets the cuber pick where to continue.
+    pub fn cube(&self, vars: &mut AstVector<'ctx>, backtrack_level: u32) -> Vec<ast::Bool<'ctx>> {
+        assert_eq!(self.ctx, vars.ctx);
+        let cube = unsafe {
+            AstVector::wrap(
+                self.ctx,
+                Z3_solver_cube(
+                    self.ctx.z3_ctx,
+                    self.z3_slv,
+                    vars.z3_ast_vector,
+                    backtrack_level,
+                ),
+            )
+        };
+        cube.iter().map(|lit| lit.as_bool().unwrap()).collect()
     }
 
     /// Create a backtracking point.
@@ -226,6 +400,16 @@ impl<'ctx> Solver<'ctx> {
         unsafe { Z3_solver_pop(self.ctx.z3_ctx, self.z3_slv, n) };
     }
 
+    /// Return the number of backtracking points.
+    ///
+    /// # See also:
+    ///
+    /// - [`Solver::push()`]
+    /// - [`Solver::pop()`]
+    pub fn get_num_scopes(&self) -> u32 {
+        unsafe { Z3_solver_get_num_scopes(self.ctx.z3_ctx, self.z3_slv) }
+    }
+
     /// Retrieve the model for the last [`Solver::check()`]
     /// or [`Solver::check_assumptions()`].
     ///
@@ -249,9 +433,9 @@ impl<'ctx> Solver<'ctx> {
     ///
     /// [proof generation is not enabled]: crate::Config::set_proof_generation
     //
-    // This seems to actually return an Ast with kind `SortKind::Unknown`, which we don't
-    // have an Ast subtype for yet.
-    pub fn get_proof(&self) -> Option<impl Ast<'ctx>> {
+    // The proof term has sort kind `SortKind::Unknown`, which we don't have an
+    // Ast subtype for, hence the `Dynamic`.
+    pub fn get_proof(&self) -> Option<ast::Dynamic<'ctx>> {
         let m = unsafe { Z3_solver_get_proof(self.ctx.z3_ctx, self.z3_slv) };
         if !m.is_null() {
             Some(unsafe { ast::Dynamic::wrap(self.ctx, m) })
@@ -314,3 +498,30 @@ impl<'ctx> Drop for Solver<'ctx> {
         unsafe { Z3_solver_dec_ref(self.ctx.z3_ctx, self.z3_slv) };
     }
 }
+
+impl SatResult {
+    /// Return `true` if the result is [`SatResult::Sat`].
+    pub fn is_sat(&self) -> bool {
+        *self == SatResult::Sat
+    }
+
+    /// Return `true` if the result is [`SatResult::Unsat`].
+    pub fn is_unsat(&self) -> bool {
+        *self == SatResult::Unsat
+    }
+
+    /// Return `true` if the result is [`SatResult::Unknown`].
+    pub fn is_unknown(&self) -> bool {
+        *self == SatResult::Unknown
+    }
+}
+
+impl fmt::Display for SatResult {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        match self {
+            SatResult::Sat => write!(f, "sat"),
+            SatResult::Unsat => write!(f, "unsat"),
+            SatResult::Unknown => write!(f, "unknown"),
+        }
+    }
+}
@@ -14,6 +14,9 @@ impl<'ctx> Sort<'ctx> {
         Sort { ctx, z3_sort }
     }
 
+    /// Create an uninterpreted sort with the given `name`. Its elements
+    /// have no structure beyond equality, and two sorts with the same name
+    /// are the same sort.
     pub fn uninterpreted(ctx: &'ctx Context, name: Symbol) -> Sort<'ctx> {
         unsafe {
             Self::wrap(
@@ -156,6 +159,69 @@ impl<'ctx> Sort<'ctx> {
         (sort, enum_consts, enum_testers)
     }
 
+    /// Create a tuple sort named `name` with the given fields.
+    ///
+    /// Returns the sort, its constructor, and one projection function per
+    /// field, in the order of `field_names`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use z3::{ast, Config, Context, SatResult, Solver, Sort, Symbol};
+    /// # use z3::ast::Ast;
+    /// # let cfg = Config::new();
+    /// # let ctx = Context::new(&cfg);
+    /// # let solver = Solver::new(&ctx);
+    /// let (pair, mk_pair, proj) = Sort::tuple(
+    ///     &ctx,
+    ///     "Pair".into(),
+    ///     &["first".into(), "second".into()],
+    ///     &[&Sort::int(&ctx), &Sort::bool(&ctx)],
+    /// );
+    ///
+    /// let p = mk_pair.apply(&[&ast::Int::from_i64(&ctx, 7), &ast::Bool::from_bool(&ctx, true)]);
+    /// assert_eq!(p.get_sort(), pair);
+    /// let first = proj[0].apply(&[&p]).as_int().unwrap();
+    /// solver.assert(&first._eq(&ast::Int::from_i64(&ctx, 7)).not());
+    /// assert_eq!(solver.check(), SatResult::Unsat);
+    /// ```
+    pub fn tuple(
+        ctx: &'ctx Context,
+        name: Symbol,
+        field_names: &[Symbol],
+        field_sorts: &[&Sort<'ctx>],
+    ) -> (Sort<'ctx>, FuncDecl<'ctx>, Vec<FuncDecl<'ctx>>) {
+        assert_eq!(field_names.len(), field_sorts.len());
+        assert!(field_sorts.iter().all(|s| s.ctx.z3_ctx == ctx.z3_ctx));
+
+        let field_names: Vec<_> = field_names.iter().map(|s| s.as_z3_symbol(ctx)).collect();
+        let field_sorts: Vec<_> = field_sorts.iter().map(|s| s.z3_sort).collect();
+        let mut mk_tuple_decl = std::ptr::null_mut();
+        let mut proj_decls = vec![std::ptr::null_mut(); field_names.len()];
+
+        let sort = unsafe {
+            Self::wrap(
+                ctx,
+                Z3_mk_tuple_sort(
+                    ctx.z3_ctx,
+                    name.as_z3_symbol(ctx),
+                    field_names.len().try_into().unwrap(),
+                    field_names.as_ptr(),
+                    field_sorts.as_ptr(),
+                    &mut mk_tuple_decl,
+                    proj_decls.as_mut_ptr(),
+                ),
+            )
+        };
+
+        let mk_tuple_decl = unsafe { FuncDecl::wrap(ctx, mk_tuple_decl) };
+        let proj_decls = proj_decls
+            .into_iter()
+            .map(|d| unsafe { FuncDecl::wrap(ctx, d) })
+            .collect();
+
+        (sort, mk_tuple_decl, proj_decls)
+    }
+
     pub fn kind(&self) -> SortKind {
         unsafe { Z3_get_sort_kind(self.ctx.z3_ctx, self.z3_sort) }
     }
@@ -180,6 +246,16 @@ impl<'ctx> Sort<'ctx> {
         }
     }
 
+    /// Returns `Some(s)` where `s` is the number of bits if the sort is a
+    /// bitvector and `None` otherwise.
+    pub fn bv_size(&self) -> Option<u32> {
+        if self.kind() == SortKind::BV {
+            Some(unsafe { Z3_get_bv_sort_size(self.ctx.z3_ctx, self.z3_sort) })
+        } else {
+            None
+        }
+    }
+
     /// Return if this Sort is for an `Array` or a `Set`.
     ///
     /// # Examples
@@ -87,7 +87,14 @@ impl<'ctx> Clone for Statistics<'ctx> {
 
 impl<'ctx> fmt::Display for Statistics<'ctx> {
     fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
-        write!(f, "<z3.stats>")
+        let p = unsafe { Z3_stats_to_string(self.ctx.z3_ctx, self.z3_stats) };
+        if p.is_null() {
+            return Result::Err(fmt::Error);
+        }
+        match unsafe { CStr::from_ptr(p) }.to_str() {
+            Ok(s) => write!(f, "{}", s),
+            Err(_) => Result::Err(fmt::Error),
+        }
     }
 }
 
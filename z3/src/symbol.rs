@@ -1,20 +1,47 @@
+use std::convert::TryFrom;
 use std::ffi::{CStr, CString};
+use std::fmt;
 use z3_sys::*;
 use Context;
 use Symbol;
 
 impl Symbol {
+    /// Create the Z3 representation of this symbol in the given context.
+    ///
+    /// # Panics
+    ///
+    /// Panics if this is a `Symbol::String` containing an interior NUL
+    /// byte, since such a string cannot be passed to Z3.
+    ///
+    /// Panics if this is a `Symbol::Int` greater than `i32::MAX`. Z3 stores
+    /// integer symbols as a non-negative C `int`, so larger values would
+    /// otherwise wrap around and not round-trip through
+    /// [`Symbol::from_z3_symbol()`].
     pub fn as_z3_symbol(&self, ctx: &Context) -> Z3_symbol {
         match self {
-            Symbol::Int(i) => unsafe { Z3_mk_int_symbol(ctx.z3_ctx, *i as ::std::os::raw::c_int) },
+            Symbol::Int(i) => {
+                let i = ::std::os::raw::c_int::try_from(*i)
+                    .expect("integer symbol does not fit in a C int");
+                unsafe { Z3_mk_int_symbol(ctx.z3_ctx, i) }
+            }
             Symbol::String(s) => {
-                let ss = CString::new(s.clone()).unwrap();
-                let p = ss.as_ptr();
-                unsafe { Z3_mk_string_symbol(ctx.z3_ctx, p) }
+                // `Z3_mk_string_symbol` reads from the pointer during the
+                // call, so the `CString` must stay bound until it returns.
+                let name = CString::new(s.as_str()).expect("symbol name contains a NUL byte");
+                unsafe { Z3_mk_string_symbol(ctx.z3_ctx, name.as_ptr()) }
             }
         }
     }
 
+    /// Return the string payload of this symbol, or `None` if it is an
+    /// integer symbol.
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Symbol::Int(_) => None,
+            Symbol::String(s) => Some(s),
+        }
+    }
+
     pub fn from_z3_symbol(ctx: &Context, symbol: Z3_symbol) -> Symbol {
         unsafe {
             match Z3_get_symbol_kind(ctx.z3_ctx, symbol) {
@@ -29,6 +56,17 @@ impl Symbol {
     }
 }
 
+impl fmt::Display for Symbol {
+    /// Integer symbols are printed as their number and string symbols as
+    /// their raw text, without any SMT-LIB `|...|` quoting.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Symbol::Int(i) => write!(f, "{}", i),
+            Symbol::String(s) => write!(f, "{}", s),
+        }
+    }
+}
+
 impl From<u32> for Symbol {
     fn from(val: u32) -> Self {
         Symbol::Int(val)
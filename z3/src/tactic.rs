@@ -204,11 +204,10 @@ impl<'ctx> Tactic<'ctx> {
                 ),
             };
             if z3_apply_result.is_null() {
-                let code = Z3_get_error_code(self.ctx.z3_ctx);
-                let msg = Z3_get_error_msg(self.ctx.z3_ctx, code);
-                Err(String::from(CStr::from_ptr(msg).to_str().unwrap_or(
-                    "Couldn't retrieve error message from z3: got invalid UTF-8",
-                )))
+                Err(match self.ctx.check_error() {
+                    Err(e) => e.to_string(),
+                    Ok(()) => String::from("Z3_tactic_apply returned null"),
+                })
             } else {
                 Ok(ApplyResult::wrap(self.ctx, z3_apply_result))
             }
@@ -0,0 +1,45 @@
+use std::ffi::CString;
+
+use z3_sys::*;
+
+/// Enable tracing messages tagged as `tag`.
+///
+/// This only has an effect if Z3 was compiled in debug mode.
+///
+/// # See also:
+///
+/// - [`disable_trace()`]
+pub fn enable_trace(tag: &str) {
+    let tag = CString::new(tag).unwrap();
+    unsafe { Z3_enable_trace(tag.as_ptr()) };
+}
+
+/// Disable tracing messages tagged as `tag`.
+///
+/// This only has an effect if Z3 was compiled in debug mode.
+///
+/// # See also:
+///
+/// - [`enable_trace()`]
+pub fn disable_trace(tag: &str) {
+    let tag = CString::new(tag).unwrap();
+    unsafe { Z3_disable_trace(tag.as_ptr()) };
+}
+
+/// Log all calls into the Z3 API to the file at `path`, so they can be
+/// replayed later. Returns `false` if the file could not be opened.
+///
+/// The log is global: it records calls from all contexts and threads.
+///
+/// # See also:
+///
+/// - [`close_log()`]
+pub fn open_log(path: &str) -> bool {
+    let path = CString::new(path).unwrap();
+    unsafe { Z3_open_log(path.as_ptr()) }
+}
+
+/// Close the log opened with [`open_log()`].
+pub fn close_log() {
+    unsafe { Z3_close_log() };
+}
@@ -0,0 +1,20 @@
+//! Global parameters are shared by every context in the process, so these
+//! tests live in their own binary.
+
+extern crate z3;
+use z3::*;
+
+#[test]
+fn test_global_params() {
+    set_global_param("smt.arith.random_initial_value", "true");
+    assert_eq!(
+        get_global_param("smt.arith.random_initial_value"),
+        Some("true".to_owned())
+    );
+    reset_all_global_params();
+    assert_eq!(
+        get_global_param("smt.arith.random_initial_value"),
+        Some("false".to_owned())
+    );
+    assert_eq!(get_global_param("no_such_param"), None);
+}
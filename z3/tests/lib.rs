@@ -5,6 +5,7 @@ extern crate log;
 extern crate z3;
 use std::convert::TryInto;
 use std::ops::Add;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::time::Duration;
 use z3::ast::{Ast, Bool};
 use z3::*;
@@ -1537,3 +1538,2163 @@ fn test_ast_safe_decl() {
         "ast node is not a function application, has kind Quantifier"
     );
 }
+
+#[test]
+fn test_model_func_interp() {
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+    let solver = Solver::new(&ctx);
+
+    let f = FuncDecl::new(&ctx, "f", &[&Sort::int(&ctx)], &Sort::int(&ctx));
+    let f_0 = f.apply(&[&ast::Int::from_i64(&ctx, 0)]).as_int().unwrap();
+    let f_1 = f.apply(&[&ast::Int::from_i64(&ctx, 1)]).as_int().unwrap();
+    solver.assert(&f_0._eq(&ast::Int::from_i64(&ctx, 1)));
+    solver.assert(&f_1._eq(&ast::Int::from_i64(&ctx, 2)));
+    assert_eq!(solver.check(), SatResult::Sat);
+
+    let model = solver.get_model().unwrap();
+    assert_eq!(model.get_num_funcs(), 1);
+    assert!(model.get_func_decl(1).is_none());
+    let decl = model.get_func_decl(0).unwrap();
+    assert_eq!(decl.name(), "f");
+
+    let interp = model.get_func_interp(&decl).unwrap();
+    assert_eq!(interp.get_arity(), 1);
+    let entries = interp.get_entries();
+    assert_eq!(entries.len() as u32, interp.get_num_entries());
+
+    let lookup = |arg: i64| {
+        entries
+            .iter()
+            .find(|e| e.get_args()[0].as_int().unwrap().as_i64() == Some(arg))
+            .map(|e| e.get_value())
+            .unwrap_or_else(|| interp.get_else())
+            .as_int()
+            .unwrap()
+            .as_i64()
+    };
+    for entry in &entries {
+        assert_eq!(entry.get_num_args(), 1);
+    }
+    assert_eq!(lookup(0), Some(1));
+    assert_eq!(lookup(1), Some(2));
+}
+
+#[test]
+fn test_model_iter() {
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+    let solver = Solver::new(&ctx);
+
+    let x = ast::Int::new_const(&ctx, "x");
+    let b = ast::Bool::new_const(&ctx, "b");
+    solver.assert(&x._eq(&ast::Int::from_i64(&ctx, 7)));
+    solver.assert(&b);
+    assert_eq!(solver.check(), SatResult::Sat);
+
+    let model = solver.get_model().unwrap();
+    let iter = model.iter();
+    assert_eq!(iter.len(), 2);
+
+    let mut pairs: Vec<(String, String)> = iter
+        .map(|(decl, value)| (decl.name(), value.to_string()))
+        .collect();
+    pairs.sort();
+    assert_eq!(
+        pairs,
+        vec![
+            ("b".to_string(), "true".to_string()),
+            ("x".to_string(), "7".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn test_model_sort_universe() {
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+    let solver = Solver::new(&ctx);
+
+    let u = Sort::uninterpreted(&ctx, "U".into());
+    let a = FuncDecl::new(&ctx, "a", &[], &u).apply(&[]);
+    let b = FuncDecl::new(&ctx, "b", &[], &u).apply(&[]);
+    solver.assert(&ast::Dynamic::distinct(&ctx, &[&a, &b]));
+    assert_eq!(solver.check(), SatResult::Sat);
+
+    let model = solver.get_model().unwrap();
+    assert_eq!(model.get_num_sorts(), 1);
+    assert_eq!(model.get_sort(0).unwrap(), u);
+    assert!(model.get_sort(1).is_none());
+    assert_eq!(model.get_sort_universe(&u).len(), 2);
+
+    // Sorts that the model does not interpret have no universe.
+    assert!(model.get_sort_universe(&Sort::int(&ctx)).is_empty());
+}
+
+#[test]
+fn test_model_has_interp() {
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+    let solver = Solver::new(&ctx);
+
+    let x = ast::Int::new_const(&ctx, "x");
+    let y = ast::Int::new_const(&ctx, "y");
+    solver.assert(&x._eq(&ast::Int::from_i64(&ctx, 1)));
+    assert_eq!(solver.check(), SatResult::Sat);
+
+    let model = solver.get_model().unwrap();
+    assert!(model.has_interp(&x.decl()));
+    assert!(!model.has_interp(&y.decl()));
+
+    // Evaluating without model completion must not assign `y`.
+    assert_eq!(model.eval(&y, false).unwrap(), y);
+    assert!(!model.has_interp(&y.decl()));
+}
+
+#[test]
+fn test_model_eval_many() {
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+    let solver = Solver::new(&ctx);
+
+    let x = ast::Int::new_const(&ctx, "x");
+    let b = ast::Bool::new_const(&ctx, "b");
+    solver.assert(&x._eq(&ast::Int::from_i64(&ctx, 4)));
+    solver.assert(&b.not());
+    assert_eq!(solver.check(), SatResult::Sat);
+
+    let model = solver.get_model().unwrap();
+    let terms: Vec<ast::Dynamic> = vec![
+        ast::Int::add(&ctx, &[&x, &ast::Int::from_i64(&ctx, 1)]).into(),
+        b.clone().into(),
+        x.gt(&ast::Int::from_i64(&ctx, 3)).into(),
+    ];
+    let results = model.eval_many(&terms, true);
+    assert_eq!(results.len(), terms.len());
+    assert_eq!(
+        results[0].as_ref().unwrap().as_int().unwrap().as_i64(),
+        Some(5)
+    );
+    assert_eq!(
+        results[1].as_ref().unwrap().as_bool().unwrap().as_bool(),
+        Some(false)
+    );
+    assert_eq!(
+        results[2].as_ref().unwrap().as_bool().unwrap().as_bool(),
+        Some(true)
+    );
+
+    // Without model completion, `y` is not assigned and stays symbolic;
+    // every result still lines up with its input term.
+    let y = ast::Int::new_const(&ctx, "y");
+    let terms: Vec<ast::Dynamic> = vec![
+        x.clone().into(),
+        y.clone().into(),
+        ast::Int::add(&ctx, &[&x, &y]).into(),
+        b.clone().into(),
+    ];
+    let results = model.eval_many(&terms, false);
+    assert_eq!(results.len(), terms.len());
+    for (term, result) in terms.iter().zip(&results) {
+        assert_eq!(result, &model.eval(term, false));
+    }
+    assert_eq!(results[1], Some(y.into()));
+
+    // A pending interrupt makes every evaluation fail, which yields one
+    // `None` per input term.
+    ctx.interrupt();
+    let results = model.eval_many(&terms, false);
+    assert_eq!(results.len(), terms.len());
+    assert!(results.iter().all(Option::is_none));
+}
+
+#[test]
+fn test_symbol_hash() {
+    use std::collections::HashSet;
+
+    let mut symbols = HashSet::new();
+    symbols.insert(Symbol::from(7));
+    symbols.insert(Symbol::from("x"));
+    symbols.insert(Symbol::from(String::from("x")));
+
+    assert_eq!(symbols.len(), 2);
+    assert!(symbols.contains(&Symbol::Int(7)));
+    assert!(symbols.contains(&Symbol::String("x".to_owned())));
+    assert!(!symbols.contains(&Symbol::Int(8)));
+    assert_ne!(Symbol::from(7), Symbol::from("7"));
+}
+
+#[test]
+fn test_symbol_display() {
+    assert_eq!(Symbol::from(42).to_string(), "42");
+    assert_eq!(Symbol::from("x").to_string(), "x");
+    assert_eq!(Symbol::from("hello world|").to_string(), "hello world|");
+}
+
+#[test]
+fn test_symbol_as_str() {
+    assert_eq!(Symbol::from("foo").as_str(), Some("foo"));
+    assert_eq!(Symbol::from(3).as_str(), None);
+}
+
+#[test]
+#[should_panic(expected = "NUL")]
+fn test_symbol_with_nul_panics() {
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+    let _ = ast::Bool::new_const(&ctx, "a\0b");
+}
+
+#[test]
+fn test_symbol_large_int_round_trip() {
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+    let name = i32::MAX as u32;
+    let f = FuncDecl::new(&ctx, name, &[], &Sort::int(&ctx));
+    assert_eq!(f.name_symbol(), Symbol::Int(name));
+}
+
+#[test]
+#[should_panic(expected = "does not fit")]
+fn test_symbol_int_out_of_range_panics() {
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+    let _ = FuncDecl::new(&ctx, u32::MAX, &[], &Sort::int(&ctx));
+}
+
+#[test]
+fn test_model_to_smt2_round_trip() {
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+    let solver = Solver::new(&ctx);
+
+    let int = Sort::int(&ctx);
+    let x = ast::Int::new_const(&ctx, "x");
+    let b = ast::Bool::new_const(&ctx, "needs quoting");
+    let f = FuncDecl::new(&ctx, "f", &[&int, &int], &int);
+    let one = ast::Int::from_i64(&ctx, 1);
+    let two = ast::Int::from_i64(&ctx, 2);
+    solver.assert(&x._eq(&ast::Int::from_i64(&ctx, -4)));
+    solver.assert(&b);
+    solver.assert(
+        &f.apply(&[&one, &two])
+            ._eq(&ast::Int::from_i64(&ctx, 7).into()),
+    );
+    solver.assert(
+        &f.apply(&[&two, &one])
+            ._eq(&ast::Int::from_i64(&ctx, 8).into()),
+    );
+    // Constants named like the parameters of `f` must not be captured.
+    for i in 0..4 {
+        let c = ast::Int::new_const(&ctx, format!("x!{}", i));
+        solver.assert(&c._eq(&ast::Int::from_i64(&ctx, 10 + i)));
+    }
+    assert_eq!(solver.check(), SatResult::Sat);
+
+    let model = solver.get_model().unwrap();
+    let smt2 = model.to_smt2();
+    assert!(smt2.contains("(define-fun x () Int"));
+    // Display keeps using the default print mode.
+    assert!(model.to_string().contains("x -> (- 4)"));
+    assert!(smt2.contains("|needs quoting|"));
+
+    let fresh = Solver::new(&ctx);
+    fresh.from_string(format!(
+        "{}(assert (= x (- 4)))(assert |needs quoting|)(assert (= (f 1 2) 7))(assert (= (f 2 1) 8))",
+        smt2
+    ));
+    assert!(ctx.check_error().is_ok());
+    assert_eq!(fresh.check(), SatResult::Sat);
+
+    let fresh = Solver::new(&ctx);
+    fresh.from_string(format!("{}(assert (not (= (f 2 1) 8)))", smt2));
+    assert_eq!(fresh.check(), SatResult::Unsat);
+}
+
+#[test]
+fn test_model_to_smt2_function_using_function() {
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+
+    // `f` is defined in terms of `g`, so `g` must be defined first.
+    let funcs = "(declare-fun g (Int) Int) (declare-fun f (Int) Int)
+        (assert (forall ((y Int)) (= (f y) (+ 1 (g y)))))
+        (assert (= (g 1) 2)) (assert (= (f 5) 3))";
+    let solver = Solver::new(&ctx);
+    solver.from_string(funcs);
+    assert_eq!(solver.check(), SatResult::Sat);
+    let smt2 = solver.get_model().unwrap().to_smt2();
+
+    let assertions = funcs.split("(assert").skip(1).collect::<Vec<_>>();
+    let fresh = Solver::new(&ctx);
+    fresh.from_string(format!("{}(assert{}", smt2, assertions.join("(assert")));
+    assert!(ctx.check_error().is_ok(), "{}", smt2);
+    assert_eq!(fresh.check(), SatResult::Sat, "{}", smt2);
+}
+
+#[test]
+fn test_model_try_get_const_interp() {
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+    let solver = Solver::new(&ctx);
+
+    let b = ast::Bool::new_const(&ctx, "b");
+    solver.assert(&b);
+    assert_eq!(solver.check(), SatResult::Sat);
+
+    let model = solver.get_model().unwrap();
+    let decl = b.decl();
+
+    let ok: Option<ast::Bool> = model.try_get_const_interp(&decl).unwrap();
+    assert_eq!(ok.unwrap().as_bool(), Some(true));
+
+    let err = model.try_get_const_interp::<ast::Int>(&decl).unwrap_err();
+    assert_eq!(*err.actual(), Sort::bool(&ctx));
+    assert!(err.expected().contains("Int"));
+    assert!(err.to_string().contains("Bool"));
+}
+
+#[test]
+fn test_model_eval_to_string() {
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+    let solver = Solver::new(&ctx);
+
+    let x = ast::Int::new_const(&ctx, "x");
+    solver.assert(&x._eq(&ast::Int::from_i64(&ctx, -3)));
+    assert_eq!(solver.check(), SatResult::Sat);
+
+    let model = solver.get_model().unwrap();
+    let expr = ast::Int::add(&ctx, &[&x, &ast::Int::from_i64(&ctx, 1)]);
+    assert_eq!(model.eval_to_string(&expr, true), Some("(- 2)".to_owned()));
+}
+
+#[test]
+fn test_model_eval_i64_f64() {
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+    let solver = Solver::new(&ctx);
+
+    let x = ast::Int::new_const(&ctx, "x");
+    let big = ast::Int::new_const(&ctx, "big");
+    let huge = ast::Int::new_const(&ctx, "huge");
+    let r = ast::Real::new_const(&ctx, "r");
+    solver.assert(&x._eq(&ast::Int::from_i64(&ctx, -12)));
+    solver.assert(&big._eq(&ast::Int::from_str(&ctx, "1180591620717411303424").unwrap()));
+    solver.assert(&huge._eq(&ast::Int::from_str(&ctx, &format!("1{}", "0".repeat(400))).unwrap()));
+    solver.assert(&r._eq(&ast::Real::from_real(&ctx, 5, 2)));
+    assert_eq!(solver.check(), SatResult::Sat);
+
+    let model = solver.get_model().unwrap();
+    assert_eq!(model.eval_i64(&x, true), Some(-12));
+    assert_eq!(model.eval_f64(&x, true), Some(-12.0));
+    assert_eq!(model.eval_i64(&big, true), None);
+    assert_eq!(model.eval_f64(&big, true), Some(2f64.powi(70)));
+    assert_eq!(model.eval_f64(&huge, true), None);
+    assert_eq!(model.eval_f64(&r, true), Some(2.5));
+    assert_eq!(model.eval_i64(&x.gt(&big), true), None);
+}
+
+#[test]
+fn test_model_clone() {
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+    let solver = Solver::new(&ctx);
+
+    let x = ast::Int::new_const(&ctx, "x");
+    solver.assert(&x._eq(&ast::Int::from_i64(&ctx, 9)));
+    assert_eq!(solver.check(), SatResult::Sat);
+
+    let model = solver.get_model().unwrap();
+    let cloned = model.clone();
+    drop(model);
+    solver.reset();
+
+    let val: ast::Int = cloned.get_const_interp(&x.decl()).unwrap();
+    assert_eq!(val.as_i64(), Some(9));
+}
+
+#[test]
+fn test_forall_reflexivity_is_valid() {
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+    let solver = Solver::new(&ctx);
+
+    let x = ast::Int::new_const(&ctx, "x");
+    let forall = ast::forall_const(&ctx, &[&x], &[], &x._eq(&x));
+    solver.assert(&forall.not());
+    assert_eq!(solver.check(), SatResult::Unsat);
+
+    let body = x._eq(&ast::Int::from_i64(&ctx, 0));
+    assert_eq!(ast::forall_const(&ctx, &[], &[], &body), body);
+    assert_eq!(ast::exists_const(&ctx, &[], &[], &body), body);
+}
+
+#[test]
+fn test_pattern_in_forall() {
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+    let solver = Solver::new(&ctx);
+
+    let f = FuncDecl::new(&ctx, "f", &[&Sort::int(&ctx)], &Sort::int(&ctx));
+    let x = ast::Int::new_const(&ctx, "x");
+    let f_x: ast::Int = f.apply(&[&x]).try_into().unwrap();
+    let pattern = Pattern::new(&ctx, &[&f_x]);
+    assert!(pattern.to_string().contains("(f x)"));
+
+    let forall = ast::forall_const(&ctx, &[&x], &[&pattern], &f_x._eq(&x));
+    solver.assert(&forall);
+
+    let three = ast::Int::from_i64(&ctx, 3);
+    let f_three: ast::Int = f.apply(&[&three]).try_into().unwrap();
+    solver.assert(&f_three._eq(&three).not());
+    assert_eq!(solver.check(), SatResult::Unsat);
+}
+
+#[test]
+fn test_lambda_const() {
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+    let solver = Solver::new(&ctx);
+
+    let x = ast::Int::new_const(&ctx, "x");
+    let body = x.gt(&ast::Int::from_i64(&ctx, 10));
+    let arr = ast::lambda_const(&ctx, &[&x], &body);
+    assert_eq!(
+        arr.get_sort(),
+        Sort::array(&ctx, &Sort::int(&ctx), &Sort::bool(&ctx))
+    );
+
+    let y = ast::Int::new_const(&ctx, "y");
+    let selected = arr.select(&y).as_bool().unwrap();
+    solver.assert(&selected._eq(&y.gt(&ast::Int::from_i64(&ctx, 10))).not());
+    assert_eq!(solver.check(), SatResult::Unsat);
+
+    let model_solver = Solver::new(&ctx);
+    model_solver.assert(&y._eq(&ast::Int::from_i64(&ctx, 11)));
+    assert_eq!(model_solver.check(), SatResult::Sat);
+    let model = model_solver.get_model().unwrap();
+    assert_eq!(model.eval(&selected, true).unwrap().as_bool(), Some(true));
+}
+
+#[test]
+fn test_solver_push_pop_scopes() {
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+    let solver = Solver::new(&ctx);
+
+    assert_eq!(solver.get_num_scopes(), 0);
+    solver.push();
+    assert_eq!(solver.get_num_scopes(), 1);
+    solver.assert(&ast::Bool::from_bool(&ctx, false));
+    assert_eq!(solver.check(), SatResult::Unsat);
+
+    solver.pop(1);
+    assert_eq!(solver.get_num_scopes(), 0);
+    assert_eq!(solver.check(), SatResult::Sat);
+}
+
+#[test]
+fn test_unsat_core_excludes_irrelevant_literals() {
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+    let solver = Solver::new(&ctx);
+
+    let x = ast::Int::new_const(&ctx, "x");
+    let y = ast::Int::new_const(&ctx, "y");
+
+    let x_pos = ast::Bool::new_const(&ctx, "x-pos");
+    let x_neg = ast::Bool::new_const(&ctx, "x-neg");
+    let y_pos = ast::Bool::new_const(&ctx, "y-pos");
+    let zero = ast::Int::from_i64(&ctx, 0);
+    solver.assert_and_track(&x.gt(&zero), &x_pos);
+    solver.assert_and_track(&x.lt(&zero), &x_neg);
+    solver.assert_and_track(&y.gt(&zero), &y_pos);
+
+    assert_eq!(solver.check(), SatResult::Unsat);
+    let unsat_core = solver.get_unsat_core();
+    assert!(unsat_core.contains(&x_pos));
+    assert!(unsat_core.contains(&x_neg));
+    assert!(!unsat_core.contains(&y_pos));
+}
+
+#[test]
+fn test_check_assumptions_toggle() {
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+    let solver = Solver::new(&ctx);
+
+    let x = ast::Int::new_const(&ctx, "x");
+    let p = ast::Bool::new_const(&ctx, "p");
+    let q = ast::Bool::new_const(&ctx, "q");
+    solver.assert(&p.implies(&x.gt(&ast::Int::from_i64(&ctx, 0))));
+    solver.assert(&q.implies(&x.lt(&ast::Int::from_i64(&ctx, 0))));
+
+    assert_eq!(
+        solver.check_assumptions(std::slice::from_ref(&p)),
+        SatResult::Sat
+    );
+    assert_eq!(
+        solver.check_assumptions(std::slice::from_ref(&q)),
+        SatResult::Sat
+    );
+    assert_eq!(
+        solver.check_assumptions(&[p.clone(), q.clone()]),
+        SatResult::Unsat
+    );
+    let core = solver.get_unsat_core();
+    assert_eq!(core.len(), 2);
+    assert!(core.contains(&p) && core.contains(&q));
+
+    assert_eq!(solver.check_assumptions(&[p.not(), q]), SatResult::Sat);
+}
+
+#[test]
+fn test_solver_get_assertions() {
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+    let solver = Solver::new(&ctx);
+    assert!(solver.get_assertions().is_empty());
+
+    let x = ast::Int::new_const(&ctx, "x");
+    let a = x.gt(&ast::Int::from_i64(&ctx, 0));
+    let b = x.lt(&ast::Int::from_i64(&ctx, 10));
+    let c = ast::Bool::new_const(&ctx, "c");
+    solver.assert(&a);
+    solver.assert(&b);
+    solver.assert(&c);
+
+    assert_eq!(solver.get_assertions(), vec![a, b, c]);
+}
+
+#[test]
+fn test_solver_reset() {
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+    let solver = Solver::new(&ctx);
+
+    solver.push();
+    solver.assert(&ast::Bool::from_bool(&ctx, false));
+    assert_eq!(solver.check(), SatResult::Unsat);
+
+    solver.reset();
+    assert_eq!(solver.get_num_scopes(), 0);
+    assert!(solver.get_assertions().is_empty());
+    assert_eq!(solver.check(), SatResult::Sat);
+}
+
+#[test]
+fn test_solver_statistics() {
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+    let solver = Solver::new(&ctx);
+
+    let x = ast::Int::new_const(&ctx, "x");
+    let y = ast::Int::new_const(&ctx, "y");
+    solver.assert(&ast::Int::add(&ctx, &[&x, &y])._eq(&ast::Int::from_i64(&ctx, 10)));
+    solver.assert(&x.gt(&y));
+    solver.assert(&y.gt(&ast::Int::from_i64(&ctx, 2)));
+    assert_eq!(solver.check(), SatResult::Sat);
+
+    let stats = solver.get_statistics();
+    let entries: Vec<_> = stats.entries().collect();
+    assert!(!entries.is_empty());
+    assert!(stats.value("rlimit count").is_some());
+    assert!(stats.to_string().contains(":rlimit-count"));
+}
+
+#[test]
+fn test_solver_get_proof() {
+    let mut cfg = Config::new();
+    cfg.set_proof_generation(true);
+    let ctx = Context::new(&cfg);
+    let solver = Solver::new(&ctx);
+
+    let x = ast::Int::new_const(&ctx, "x");
+    solver.assert(&x.gt(&ast::Int::from_i64(&ctx, 2)));
+    solver.assert(&x.lt(&ast::Int::from_i64(&ctx, 1)));
+    assert_eq!(solver.check(), SatResult::Unsat);
+
+    let proof = solver.get_proof().unwrap();
+    assert!(proof.is_app());
+
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+    let solver = Solver::new(&ctx);
+    solver.assert(&ast::Bool::from_bool(&ctx, false));
+    assert_eq!(solver.check(), SatResult::Unsat);
+    assert!(solver.get_proof().is_none());
+}
+
+#[test]
+fn test_solver_smt2_round_trip() {
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+    let solver = Solver::new(&ctx);
+
+    let x = ast::Int::new_const(&ctx, "x");
+    let y = ast::Int::new_const(&ctx, "y");
+    solver.assert(&x.gt(&y));
+    solver.assert(&y.gt(&ast::Int::from_i64(&ctx, 5)));
+    solver.assert(&x.lt(&ast::Int::from_i64(&ctx, 7)));
+    let smt2 = solver.to_smt2();
+    assert!(smt2.contains("(declare-fun x () Int)"));
+
+    let assertions = ctx.from_smt2(&smt2).unwrap();
+    assert_eq!(assertions.len(), 3);
+    let fresh = Solver::new(&ctx);
+    for a in &assertions {
+        fresh.assert(a);
+    }
+    assert_eq!(solver.check(), SatResult::Unsat);
+    assert_eq!(fresh.check(), SatResult::Unsat);
+
+    let empty = Solver::new(&ctx);
+    assert!(!empty.to_smt2().contains("set-logic"));
+    assert!(ctx.from_smt2(&empty.to_smt2()).is_ok());
+
+    let lia = Solver::new_for_logic(&ctx, "QF_LIA").unwrap();
+    lia.assert(&x.gt(&y));
+    let smt2 = lia.to_smt2();
+    assert!(smt2.contains("(set-logic QF_LIA)"));
+    assert!(lia.translate(&ctx).to_smt2().contains("(set-logic QF_LIA)"));
+    assert_eq!(ctx.from_smt2(&smt2).unwrap().len(), 1);
+
+    assert!(ctx.from_smt2("(assert (> undeclared 0))").is_err());
+}
+
+#[test]
+fn test_solver_translate_outlives_source() {
+    let cfg = Config::new();
+    let destination = Context::new(&cfg);
+    let translated = {
+        let source = Context::new(&cfg);
+        let slv = Solver::new(&source);
+        let x = ast::Int::new_const(&source, "x");
+        slv.assert(&x.gt(&ast::Int::from_i64(&source, 1)));
+        slv.translate(&destination)
+    };
+
+    assert_eq!(translated.get_assertions().len(), 1);
+    assert_eq!(translated.check(), SatResult::Sat);
+    let x = ast::Int::new_const(&destination, "x");
+    let model = translated.get_model().unwrap();
+    assert!(model.eval(&x, true).unwrap().as_i64().unwrap() > 1);
+}
+
+#[test]
+fn test_solver_get_consequences() {
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+    let solver = Solver::new(&ctx);
+
+    let a = ast::Bool::new_const(&ctx, "a");
+    let b = ast::Bool::new_const(&ctx, "b");
+    let c = ast::Bool::new_const(&ctx, "c");
+    solver.assert(&ast::Bool::and(&ctx, &[&a, &a.implies(&b)]));
+
+    let (res, consequences) = solver.get_consequences(&[], &[b.clone(), c]);
+    assert_eq!(res, SatResult::Sat);
+    assert_eq!(consequences.len(), 1);
+    assert_eq!(
+        consequences[0],
+        ast::Bool::from_bool(&ctx, true).implies(&b)
+    );
+}
+
+/// `x^3 + y^3 = z^3` over the positive integers, which Z3 cannot decide.
+fn assert_hard_problem<'ctx>(ctx: &'ctx Context, solver: &Solver<'ctx>) {
+    let x = ast::Int::new_const(ctx, "x");
+    let y = ast::Int::new_const(ctx, "y");
+    let z = ast::Int::new_const(ctx, "z");
+    let zero = ast::Int::from_i64(ctx, 0);
+    let cube = |v: &ast::Int<'ctx>| ast::Int::mul(ctx, &[v, v, v]);
+    solver.assert(&x.gt(&zero));
+    solver.assert(&y.gt(&zero));
+    solver.assert(&z.gt(&zero));
+    solver.assert(&ast::Int::add(ctx, &[&cube(&x), &cube(&y)])._eq(&cube(&z)));
+}
+
+#[test]
+fn test_context_interrupt() {
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+    let solver = Solver::new(&ctx);
+    assert_hard_problem(&ctx, &solver);
+
+    // Safety net in case no interrupt reaches the check.
+    let mut params = Params::new(&ctx);
+    params.set_u32("timeout", 60_000);
+    solver.set_params(&params);
+
+    let handle = ctx.handle();
+    let done = AtomicBool::new(false);
+    let result = std::thread::scope(|s| {
+        s.spawn(|| {
+            while !done.load(Ordering::SeqCst) {
+                handle.interrupt();
+                std::thread::sleep(Duration::from_millis(10));
+            }
+        });
+        let result = solver.check();
+        done.store(true, Ordering::SeqCst);
+        result
+    });
+    assert_eq!(result, SatResult::Unknown);
+}
+
+#[test]
+fn test_context_interrupt_idle() {
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+    let solver = Solver::new(&ctx);
+    let x = ast::Int::new_const(&ctx, "x");
+    solver.assert(&x._eq(&ast::Int::from_i64(&ctx, 4)));
+
+    ctx.interrupt();
+    assert_eq!(solver.check(), SatResult::Sat);
+    let model = solver.get_model().unwrap();
+    assert_eq!(model.eval(&x, true).unwrap().as_i64(), Some(4));
+}
+
+#[test]
+fn test_params_timeout() {
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+    let solver = Solver::new(&ctx);
+    assert_hard_problem(&ctx, &solver);
+
+    let mut params = Params::new(&ctx);
+    params.set_u32("timeout", 50);
+    solver.set_params(&params);
+    assert_eq!(solver.check(), SatResult::Unknown);
+    assert!(solver.get_reason_unknown().is_some());
+}
+
+#[test]
+fn test_bv_extract_concat() {
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+    let solver = Solver::new(&ctx);
+
+    let x = ast::BV::new_const(&ctx, "x", 16);
+    solver.assert(&x._eq(&ast::BV::from_u64(&ctx, 0xABCD, 16)));
+    let high = x.extract(15, 8);
+    let joined = high.concat(&x.extract(7, 0));
+    assert_eq!(high.get_size(), 8);
+    assert_eq!(joined.get_size(), 16);
+
+    assert_eq!(solver.check(), SatResult::Sat);
+    let model = solver.get_model().unwrap();
+    assert_eq!(model.eval(&high, true).unwrap().as_u64(), Some(0xAB));
+    assert_eq!(model.eval(&joined, true).unwrap().as_u64(), Some(0xABCD));
+}
+
+#[test]
+fn test_bv_sign_zero_ext() {
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+    let solver = Solver::new(&ctx);
+
+    let x = ast::BV::new_const(&ctx, "x", 8);
+    solver.assert(&x._eq(&ast::BV::from_u64(&ctx, 0xFF, 8)));
+    let sext = x.sign_ext(8);
+    let zext = x.zero_ext(8);
+    assert_eq!(sext.get_size(), 16);
+    assert_eq!(zext.get_size(), 16);
+
+    assert_eq!(solver.check(), SatResult::Sat);
+    let model = solver.get_model().unwrap();
+    assert_eq!(model.eval(&sext, true).unwrap().as_u64(), Some(0xFFFF));
+    assert_eq!(model.eval(&zext, true).unwrap().as_u64(), Some(0x00FF));
+}
+
+#[test]
+fn test_bv_rotate() {
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+    let solver = Solver::new(&ctx);
+
+    let x = ast::BV::new_const(&ctx, "x", 8);
+    solver.assert(&x._eq(&ast::BV::from_u64(&ctx, 0b1001_0110, 8)));
+    let three = ast::BV::from_u64(&ctx, 3, 8);
+
+    assert_eq!(solver.check(), SatResult::Sat);
+    let model = solver.get_model().unwrap();
+    let eval = |bv: &ast::BV| model.eval(bv, true).unwrap().as_u64();
+    assert_eq!(eval(&x.rotl(3)), Some(0b1011_0100));
+    assert_eq!(eval(&x.rotr(3)), Some(0b1101_0010));
+    assert_eq!(eval(&x.rotl(11)), eval(&x.rotl(3)));
+    assert_eq!(eval(&x.bvrotl(&three)), Some(0b1011_0100));
+    assert_eq!(eval(&x.bvrotr(&three)), Some(0b1101_0010));
+}
+
+#[test]
+fn test_bv_signed_unsigned_comparisons() {
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+    let solver = Solver::new(&ctx);
+
+    let ff = ast::BV::from_u64(&ctx, 0xFF, 8);
+    let zero = ast::BV::from_u64(&ctx, 0x00, 8);
+    solver.assert(&ff.bvugt(&zero));
+    solver.assert(&ff.bvuge(&zero));
+    solver.assert(&zero.bvult(&ff));
+    solver.assert(&zero.bvule(&ff));
+    solver.assert(&ff.bvslt(&zero));
+    solver.assert(&ff.bvsle(&zero));
+    solver.assert(&zero.bvsgt(&ff));
+    solver.assert(&zero.bvsge(&ff));
+    assert_eq!(solver.check(), SatResult::Sat);
+
+    solver.assert(&ff.bvsgt(&zero));
+    assert_eq!(solver.check(), SatResult::Unsat);
+}
+
+#[test]
+fn test_array_select_store_axiom() {
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+    let solver = Solver::new(&ctx);
+
+    let int = Sort::int(&ctx);
+    let a = ast::Array::new_const(&ctx, "a", &int, &int);
+    let i = ast::Int::new_const(&ctx, "i");
+    let v = ast::Int::new_const(&ctx, "v");
+    let selected = a.store(&i, &v).select(&i);
+    solver.assert(&selected._eq(&v.into()).not());
+    assert_eq!(solver.check(), SatResult::Unsat);
+}
+
+#[test]
+fn test_array_const_array() {
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+    let solver = Solver::new(&ctx);
+
+    let zero = ast::Int::from_i64(&ctx, 0);
+    let zeros = ast::Array::const_array(&ctx, &Sort::int(&ctx), &zero);
+    let i = ast::Int::new_const(&ctx, "i");
+    solver.assert(&zeros.select(&i)._eq(&zero.into()).not());
+    assert_eq!(solver.check(), SatResult::Unsat);
+}
+
+#[test]
+fn test_array_map() {
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+    let solver = Solver::new(&ctx);
+
+    let int = Sort::int(&ctx);
+    let a = ast::Array::new_const(&ctx, "a", &int, &int);
+    let ones = ast::Array::const_array(&ctx, &int, &ast::Int::from_i64(&ctx, 1));
+    let x = ast::Int::new_const(&ctx, "x");
+    let plus = ast::Int::add(&ctx, &[&x, &x]).decl();
+    let incremented = ast::Array::map(&plus, &[&a, &ones]);
+
+    let i = ast::Int::new_const(&ctx, "i");
+    let before = a.select(&i).as_int().unwrap();
+    let after = incremented.select(&i).as_int().unwrap();
+    solver.assert(
+        &after
+            ._eq(&ast::Int::add(
+                &ctx,
+                &[&before, &ast::Int::from_i64(&ctx, 1)],
+            ))
+            .not(),
+    );
+    assert_eq!(solver.check(), SatResult::Unsat);
+}
+
+#[test]
+fn test_set_empty_full_membership() {
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+    let solver = Solver::new(&ctx);
+
+    let int = Sort::int(&ctx);
+    let x = ast::Int::new_const(&ctx, "x");
+    let y = ast::Int::new_const(&ctx, "y");
+    let empty = ast::Set::empty(&ctx, &int);
+    let full = ast::Set::full(&ctx, &int);
+
+    solver.push();
+    solver.assert(&empty.add(&x).member(&x).not());
+    assert_eq!(solver.check(), SatResult::Unsat);
+    solver.pop(1);
+
+    solver.push();
+    solver.assert(&full.member(&y).not());
+    assert_eq!(solver.check(), SatResult::Unsat);
+    solver.pop(1);
+
+    solver.assert(&full.complement()._eq(&empty).not());
+    assert_eq!(solver.check(), SatResult::Unsat);
+}
+
+#[test]
+fn test_string_length() {
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+    let solver = Solver::new(&ctx);
+
+    let a = ast::String::new_const(&ctx, "a");
+    let b = ast::String::new_const(&ctx, "b");
+    let ab = ast::String::concat(&ctx, &[&a, &b]);
+
+    solver.push();
+    solver.assert(
+        &ab.length()
+            ._eq(&ast::Int::add(&ctx, &[&a.length(), &b.length()]))
+            .not(),
+    );
+    assert_eq!(solver.check(), SatResult::Unsat);
+    solver.pop(1);
+
+    solver.assert(&a.length()._eq(&ast::Int::from_i64(&ctx, 3)));
+    solver.assert(&a.contains(&ast::String::from_str(&ctx, "z").unwrap()));
+    solver.assert(&ast::String::from_str(&ctx, "zz").unwrap().prefix(&a));
+    assert_eq!(solver.check(), SatResult::Sat);
+
+    let model = solver.get_model().unwrap();
+    let value = model.eval(&a, true).unwrap().as_string().unwrap();
+    assert_eq!(value.chars().count(), 3);
+    assert!(value.starts_with("zz"));
+}
+
+#[test]
+fn test_string_in_regexp() {
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+    let solver = Solver::new(&ctx);
+
+    // ab[a-z]+(!)?
+    let re = ast::Regexp::concat(
+        &ctx,
+        &[
+            &ast::Regexp::to_re(&ctx, "ab"),
+            &ast::Regexp::range(&ctx, 'a', 'z').plus(),
+            &ast::Regexp::to_re(&ctx, "!").option(),
+        ],
+    );
+    let s = ast::String::new_const(&ctx, "s");
+    solver.assert(&s.in_re(&re));
+    solver.assert(&s.length()._eq(&ast::Int::from_i64(&ctx, 5)));
+    solver.assert(&ast::String::from_str(&ctx, "!").unwrap().suffix(&s).not());
+    assert_eq!(solver.check(), SatResult::Sat);
+
+    let model = solver.get_model().unwrap();
+    let value = model.eval(&s, true).unwrap().as_string().unwrap();
+    assert_eq!(value.len(), 5);
+    assert!(value.starts_with("ab"));
+    assert!(value.chars().all(|c| c.is_ascii_lowercase()));
+
+    let either = ast::Regexp::union(&ctx, &[&ast::Regexp::to_re(&ctx, "x"), &re.star()]);
+    assert_eq!(either.get_sort().kind(), SortKind::RE);
+    assert!(ast::Dynamic::from_ast(&either).as_regexp().is_some());
+}
+
+#[test]
+fn test_float_add_with_rounding_mode() {
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+    let solver = Solver::new(&ctx);
+
+    let x = ast::Float::new_const_double(&ctx, "x");
+    let one = ast::Float::from_f64(&ctx, 1.0);
+    let two = ast::Float::from_f64(&ctx, 2.0);
+    let rm = ast::Float::round_towards_zero(&ctx);
+    solver.assert(&rm.add(&x, &one)._eq(&two));
+    assert_eq!(solver.check(), SatResult::Sat);
+
+    let model = solver.get_model().unwrap();
+    assert_eq!(model.eval(&x, true).unwrap().as_f64(), 1.0);
+}
+
+#[test]
+fn test_float_rounding_modes() {
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+    let solver = Solver::new(&ctx);
+
+    let modes = [
+        ast::Float::round_nearest_ties_to_even(&ctx),
+        ast::Float::round_nearest_ties_to_away(&ctx),
+        ast::Float::round_towards_positive(&ctx),
+        ast::Float::round_towards_negative(&ctx),
+        ast::Float::round_towards_zero(&ctx),
+    ];
+    for (i, a) in modes.iter().enumerate() {
+        for b in &modes[i + 1..] {
+            assert_ne!(a, b);
+        }
+    }
+
+    // 1 / 3 rounded towards zero and towards positive differ by one ulp.
+    let one = ast::Float::from_f32(&ctx, 1.0);
+    let three = ast::Float::from_f32(&ctx, 3.0);
+    let rtz = modes[4].div(&one, &three);
+    let rtp = modes[2].div(&one, &three);
+    solver.assert(&rtz.lt(&rtp));
+    assert_eq!(solver.check(), SatResult::Sat);
+
+    let model = solver.get_model().unwrap();
+    let rtz = model.eval(&rtz, true).unwrap().as_f32();
+    assert!(rtz <= 1.0 / 3.0);
+}
+
+#[test]
+fn test_recursive_datatype_head_is_valid() {
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+    let solver = Solver::new(&ctx);
+
+    let list_sort = DatatypeBuilder::new(&ctx, "IntList")
+        .variant("nil", vec![])
+        .variant(
+            "cons",
+            vec![
+                ("head", DatatypeAccessor::Sort(Sort::int(&ctx))),
+                ("tail", DatatypeAccessor::Datatype("IntList".into())),
+            ],
+        )
+        .finish();
+
+    let nil = list_sort.variants[0].constructor.apply(&[]);
+    let one = ast::Int::from_i64(&ctx, 1);
+    let list = list_sort.variants[1].constructor.apply(&[&one, &nil]);
+    let head = list_sort.variants[1].accessors[0].apply(&[&list]);
+    assert_eq!(list_sort.variants[1].accessors[0].name(), "head");
+
+    solver.assert(&head._eq(&one.into()).not());
+    assert_eq!(solver.check(), SatResult::Unsat);
+}
+
+#[test]
+fn test_tuple_sort() {
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+    let solver = Solver::new(&ctx);
+
+    let (pair, mk_pair, proj) = Sort::tuple(
+        &ctx,
+        "IntPair".into(),
+        &["fst".into(), "snd".into()],
+        &[&Sort::int(&ctx), &Sort::int(&ctx)],
+    );
+    assert_eq!(pair.kind(), SortKind::Datatype);
+    assert_eq!(mk_pair.arity(), 2);
+    assert_eq!(proj.len(), 2);
+    assert_eq!(proj[1].name(), "snd");
+
+    let three = ast::Int::from_i64(&ctx, 3);
+    let four = ast::Int::from_i64(&ctx, 4);
+    let p = mk_pair.apply(&[&three, &four]);
+    let snd = proj[1].apply(&[&p]).as_int().unwrap();
+    solver.assert(&snd._eq(&four).not());
+    assert_eq!(solver.check(), SatResult::Unsat);
+}
+
+#[test]
+fn test_enumeration_sort_distinct_constants() {
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+    let solver = Solver::new(&ctx);
+
+    let (state, consts, testers) = Sort::enumeration(
+        &ctx,
+        "State".into(),
+        &["Idle".into(), "Running".into(), "Done".into()],
+    );
+    assert_eq!(state.kind(), SortKind::Datatype);
+    assert_eq!(consts.len(), 3);
+    assert_eq!(testers.len(), 3);
+
+    let idle = consts[0].apply(&[]);
+    let running = consts[1].apply(&[]);
+    solver.assert(&idle._eq(&running));
+    assert_eq!(solver.check(), SatResult::Unsat);
+
+    let s = FuncDecl::new(&ctx, "s", &[], &state).apply(&[]);
+    let solver = Solver::new(&ctx);
+    solver.assert(&s._eq(&idle).not());
+    solver.assert(&s._eq(&running).not());
+    solver.assert(&testers[2].apply(&[&s]).as_bool().unwrap().not());
+    assert_eq!(solver.check(), SatResult::Unsat);
+}
+
+#[test]
+fn test_array_sort_domain_range() {
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+
+    let int = Sort::int(&ctx);
+    let bool_sort = Sort::bool(&ctx);
+    let array = Sort::array(&ctx, &int, &bool_sort);
+    assert!(array.is_array());
+    assert_eq!(array.array_domain(), Some(int.clone()));
+    assert_eq!(array.array_range(), Some(bool_sort));
+
+    assert_eq!(int.array_domain(), None);
+    assert_eq!(int.array_range(), None);
+}
+
+#[test]
+fn test_sort_kind() {
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+
+    let int = Sort::int(&ctx);
+    assert_eq!(Sort::bool(&ctx).kind(), SortKind::Bool);
+    assert_eq!(int.kind(), SortKind::Int);
+    assert_eq!(Sort::real(&ctx).kind(), SortKind::Real);
+    assert_eq!(Sort::bitvector(&ctx, 8).kind(), SortKind::BV);
+    assert_eq!(Sort::array(&ctx, &int, &int).kind(), SortKind::Array);
+    assert_eq!(Sort::float32(&ctx).kind(), SortKind::FloatingPoint);
+    assert_eq!(Sort::string(&ctx).kind(), SortKind::Seq);
+    assert_eq!(
+        Sort::uninterpreted(&ctx, "U".into()).kind(),
+        SortKind::Uninterpreted
+    );
+}
+
+#[test]
+fn test_sort_bv_size() {
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+
+    assert_eq!(Sort::bitvector(&ctx, 32).bv_size(), Some(32));
+    assert_eq!(
+        ast::BV::new_const(&ctx, "x", 7).get_sort().bv_size(),
+        Some(7)
+    );
+    assert_eq!(Sort::int(&ctx).bv_size(), None);
+}
+
+#[test]
+fn test_func_decl_apply_binary() {
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+    let solver = Solver::new(&ctx);
+
+    let int = Sort::int(&ctx);
+    let f = FuncDecl::new(&ctx, "f", &[&int, &int], &int);
+    let a = ast::Int::new_const(&ctx, "a");
+    let b = ast::Int::new_const(&ctx, "b");
+    let f_ab = f.apply(&[&a, &b]);
+    assert_eq!(f_ab.decl().name(), "f");
+    assert_eq!(f_ab.num_children(), 2);
+
+    solver.assert(&a._eq(&b));
+    solver.assert(&f_ab._eq(&f.apply(&[&b, &a])).not());
+    assert_eq!(solver.check(), SatResult::Unsat);
+}
+
+#[test]
+#[should_panic(expected = "wrong number of arguments")]
+fn test_func_decl_apply_wrong_arity() {
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+
+    let int = Sort::int(&ctx);
+    let f = FuncDecl::new(&ctx, "f", &[&int, &int], &int);
+    let a = ast::Int::new_const(&ctx, "a");
+    f.apply(&[&a]);
+}
+
+#[test]
+fn test_func_decl_signature() {
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+
+    let f = FuncDecl::new(
+        &ctx,
+        "f",
+        &[&Sort::int(&ctx), &Sort::bool(&ctx)],
+        &Sort::real(&ctx),
+    );
+    assert_eq!(f.arity(), 2);
+    assert_eq!(f.domain(0), Some(Sort::int(&ctx)));
+    assert_eq!(f.domain(1), Some(Sort::bool(&ctx)));
+    assert_eq!(f.domain(2), None);
+    assert_eq!(f.range(), Sort::real(&ctx));
+}
+
+#[test]
+fn test_func_decl_name_symbol() {
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+
+    let foo = FuncDecl::new(&ctx, "foo", &[&Sort::int(&ctx)], &Sort::int(&ctx));
+    assert_eq!(foo.name_symbol(), Symbol::String("foo".to_owned()));
+    assert_eq!(foo.name(), "foo");
+
+    let k = FuncDecl::new(&ctx, 5, &[], &Sort::int(&ctx));
+    assert_eq!(k.name_symbol(), Symbol::Int(5));
+    assert_eq!(k.name(), "k!5");
+}
+
+#[test]
+fn test_func_decl_kind() {
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+
+    let f = FuncDecl::new(&ctx, "f", &[&Sort::int(&ctx)], &Sort::int(&ctx));
+    assert_eq!(f.kind(), DeclKind::UNINTERPRETED);
+
+    let x = ast::Int::new_const(&ctx, "x");
+    let sum = ast::Int::add(&ctx, &[&x, &ast::Int::from_i64(&ctx, 1)]);
+    assert_eq!(sum.decl().kind(), DeclKind::ADD);
+    assert_eq!(x.decl().kind(), DeclKind::UNINTERPRETED);
+}
+
+#[test]
+fn test_ast_children_traversal() {
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+
+    let a = ast::Int::new_const(&ctx, "a");
+    let b = ast::Int::new_const(&ctx, "b");
+    let sum = ast::Int::add(&ctx, &[&a, &b]);
+    assert_eq!(sum.num_children(), 2);
+    assert_eq!(sum.decl().kind(), DeclKind::ADD);
+    assert_eq!(sum.nth_child(0), Some(a.clone().into()));
+    assert_eq!(sum.nth_child(1), Some(b.clone().into()));
+    assert_eq!(sum.nth_child(2), None);
+
+    // Collect the names of all constants reachable from the term.
+    fn leaves<'ctx>(t: &ast::Dynamic<'ctx>, out: &mut Vec<String>) {
+        if t.num_children() == 0 {
+            out.push(t.decl().name());
+        }
+        for c in t.children() {
+            leaves(&c, out);
+        }
+    }
+    let mut names = vec![];
+    leaves(&sum.into(), &mut names);
+    assert_eq!(names, vec!["a", "b"]);
+}
+
+#[test]
+fn test_simplify_to_literal() {
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+
+    let one = ast::Int::from_i64(&ctx, 1);
+    let two = ast::Int::add(&ctx, &[&one, &one]).simplify();
+    assert_eq!(two, ast::Int::from_i64(&ctx, 2));
+    assert_eq!(two.as_i64(), Some(2));
+
+    let x = ast::Int::new_const(&ctx, "x");
+    let x_times_two = ast::Int::mul(&ctx, &[&x, &ast::Int::from_i64(&ctx, 2)]);
+    let mut params = Params::new(&ctx);
+    params.set_bool("som", true);
+    assert_eq!(x_times_two.simplify_ex(&params).to_string(), "(* 2 x)");
+    assert_eq!(ast::Int::add(&ctx, &[&one, &one]).simplify_ex(&params), two);
+}
+
+#[test]
+fn test_substitute_then_eval() {
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+
+    let x = ast::Int::new_const(&ctx, "x");
+    let one = ast::Int::from_i64(&ctx, 1);
+    let five = ast::Int::from_i64(&ctx, 5);
+    let x_plus_one = ast::Int::add(&ctx, &[&x, &one]);
+
+    let five_plus_one = x_plus_one.substitute(&[(&x, &five)]);
+    assert_eq!(five_plus_one, ast::Int::add(&ctx, &[&five, &one]));
+
+    let solver = Solver::new(&ctx);
+    assert_eq!(solver.check(), SatResult::Sat);
+    let model = solver.get_model().unwrap();
+    assert_eq!(model.eval(&five_plus_one, true).unwrap().as_i64(), Some(6));
+    assert_eq!(five_plus_one.simplify().as_i64(), Some(6));
+}
+
+#[test]
+fn test_ast_hash_set_dedup() {
+    use std::collections::HashSet;
+
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+
+    let x = ast::Int::new_const(&ctx, "x");
+    let y = ast::Int::new_const(&ctx, "y");
+    let a = ast::Int::add(&ctx, &[&x, &y]);
+    let b = ast::Int::add(&ctx, &[&x, &y]);
+    let c = ast::Int::add(&ctx, &[&y, &x]);
+    assert_eq!(a, b);
+
+    let mut terms = HashSet::new();
+    assert!(terms.insert(a));
+    assert!(!terms.insert(b));
+    assert!(terms.insert(c));
+    assert_eq!(terms.len(), 2);
+
+    let dynamics: HashSet<ast::Dynamic> = vec![
+        ast::Dynamic::from_ast(&x),
+        ast::Dynamic::from_ast(&ast::Int::new_const(&ctx, "x")),
+    ]
+    .into_iter()
+    .collect();
+    assert_eq!(dynamics.len(), 1);
+}
+
+#[test]
+fn test_dynamic_downcast_roundtrip() {
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+
+    let x = ast::Int::new_const(&ctx, "x");
+    let dynamic = ast::Dynamic::from_ast(&x);
+    assert_eq!(dynamic.sort_kind(), SortKind::Int);
+    assert_eq!(dynamic.as_int(), Some(x.clone()));
+    assert!(dynamic.as_bool().is_none());
+    assert!(dynamic.as_bv().is_none());
+
+    let back: ast::Int = dynamic.clone().try_into().unwrap();
+    assert_eq!(back, x);
+
+    let bv = ast::BV::new_const(&ctx, "b", 8);
+    let dynamic = ast::Dynamic::from(bv.clone());
+    assert_eq!(dynamic.as_bv(), Some(bv));
+    assert!(dynamic.as_int().is_none());
+}
+
+#[test]
+fn test_sort_equality() {
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+
+    assert_eq!(Sort::int(&ctx), Sort::int(&ctx));
+    assert_ne!(Sort::int(&ctx), Sort::real(&ctx));
+    assert_eq!(Sort::bitvector(&ctx, 8), Sort::bitvector(&ctx, 8));
+    assert_ne!(Sort::bitvector(&ctx, 8), Sort::bitvector(&ctx, 16));
+
+    let x = ast::Int::new_const(&ctx, "x");
+    let p = ast::Bool::new_const(&ctx, "p");
+    assert_eq!(x.get_sort(), Sort::int(&ctx));
+    assert_ne!(x.get_sort(), p.get_sort());
+}
+
+#[test]
+fn test_ite_under_both_conditions() {
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+
+    let c = ast::Bool::new_const(&ctx, "c");
+    let one = ast::Int::from_i64(&ctx, 1);
+    let two = ast::Int::from_i64(&ctx, 2);
+    let ite = c.ite(&one, &two);
+    assert_eq!(ite.get_sort(), Sort::int(&ctx));
+
+    let solver = Solver::new(&ctx);
+    for (cond, expected) in &[(true, 1), (false, 2)] {
+        solver.push();
+        solver.assert(&c._eq(&ast::Bool::from_bool(&ctx, *cond)));
+        assert_eq!(solver.check(), SatResult::Sat);
+        let model = solver.get_model().unwrap();
+        assert_eq!(model.eval(&ite, true).unwrap().as_i64(), Some(*expected));
+        solver.pop(1);
+    }
+}
+
+#[test]
+fn test_nary_and_or() {
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+
+    let bools: Vec<_> = (0..5)
+        .map(|i| ast::Bool::new_const(&ctx, format!("b{}", i)))
+        .collect();
+    let refs: Vec<_> = bools.iter().collect();
+    let conj = ast::Bool::and(&ctx, &refs);
+    let pairwise = bools[1..]
+        .iter()
+        .fold(bools[0].clone(), |acc, b| ast::Bool::and(&ctx, &[&acc, b]));
+
+    let solver = Solver::new(&ctx);
+    solver.assert(&conj._eq(&pairwise).not());
+    assert_eq!(solver.check(), SatResult::Unsat);
+
+    assert_eq!(ast::Bool::and(&ctx, &[]).simplify().as_bool(), Some(true));
+    assert_eq!(ast::Bool::or(&ctx, &[]).simplify().as_bool(), Some(false));
+}
+
+#[test]
+fn test_distinct_pigeonhole() {
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+
+    let one = ast::Int::from_i64(&ctx, 1);
+    let two = ast::Int::from_i64(&ctx, 2);
+    let xs: Vec<_> = (0..3)
+        .map(|i| ast::Int::new_const(&ctx, format!("x{}", i)))
+        .collect();
+
+    let solver = Solver::new(&ctx);
+    for x in &xs {
+        solver.assert(&x.ge(&one));
+        solver.assert(&x.le(&two));
+    }
+    solver.assert(&ast::Int::distinct(&ctx, &[&xs[0], &xs[1]]));
+    assert_eq!(solver.check(), SatResult::Sat);
+
+    solver.assert(&ast::Int::distinct(&ctx, &xs.iter().collect::<Vec<_>>()));
+    assert_eq!(solver.check(), SatResult::Unsat);
+}
+
+#[test]
+fn test_real_to_int_floors() {
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+
+    let seven_halves = ast::Real::from_real(&ctx, 7, 2);
+    assert_eq!(seven_halves.to_int().simplify().as_i64(), Some(3));
+    let minus_seven_halves = ast::Real::from_real(&ctx, -7, 2);
+    assert_eq!(minus_seven_halves.to_int().simplify().as_i64(), Some(-4));
+
+    assert_eq!(seven_halves.is_int().simplify().as_bool(), Some(false));
+    let three = ast::Int::from_i64(&ctx, 3);
+    assert_eq!(three.to_real().is_int().simplify().as_bool(), Some(true));
+    assert_eq!(three.to_real().simplify().as_real(), Some((3, 1)));
+
+    let x = ast::Real::new_const(&ctx, "x");
+    let solver = Solver::new(&ctx);
+    solver.assert(&x._eq(&seven_halves));
+    assert_eq!(solver.check(), SatResult::Sat);
+    let model = solver.get_model().unwrap();
+    assert_eq!(model.eval(&x.to_int(), true).unwrap().as_i64(), Some(3));
+}
+
+#[test]
+fn test_bv_int_conversions() {
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+
+    let ff = ast::BV::from_u64(&ctx, 0xFF, 8);
+    assert_eq!(ff.to_int(true).simplify().as_i64(), Some(-1));
+    assert_eq!(ff.to_int(false).simplify().as_i64(), Some(255));
+
+    let minus_one = ast::Int::from_i64(&ctx, -1);
+    let bv = minus_one.to_bv(8);
+    assert_eq!(bv.get_size(), 8);
+    assert_eq!(bv.simplify(), ff);
+}
+
+#[test]
+fn test_simplify_tactic_subgoals() {
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+
+    let x = ast::Int::new_const(&ctx, "x");
+    let one = ast::Int::from_i64(&ctx, 1);
+    let goal = Goal::new(&ctx, false, false, false);
+    goal.assert(&ast::Int::add(&ctx, &[&one, &one]).lt(&x));
+    goal.assert(&x.le(&ast::Int::from_i64(&ctx, 10)));
+    assert_eq!(goal.get_size(), 2);
+    assert!(goal.get_formula(1).is_some());
+    assert!(goal.get_formula(2).is_none());
+
+    let tactic = Tactic::new(&ctx, "simplify");
+    let subgoals: Vec<_> = tactic.apply(&goal, None).unwrap().list_subgoals().collect();
+    assert_eq!(subgoals.len(), 1);
+
+    let subgoal = subgoals[0].clone();
+    assert_eq!(subgoal.get_size(), 2);
+    let formula = subgoal.get_formula(0).unwrap().as_bool().unwrap();
+    let expected = ast::Int::from_i64(&ctx, 2).lt(&x).simplify();
+    assert_eq!(formula, expected);
+}
+
+#[test]
+fn test_tactic_composition_reduces_further() {
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+
+    let x = ast::Int::new_const(&ctx, "x");
+    let y = ast::Int::new_const(&ctx, "y");
+    let zero = ast::Int::from_i64(&ctx, 0);
+    let one = ast::Int::from_i64(&ctx, 1);
+    let three = ast::Int::from_i64(&ctx, 3);
+    let goal = Goal::new(&ctx, false, false, false);
+    goal.assert(&ast::Int::add(&ctx, &[&x, &zero])._eq(&ast::Int::add(&ctx, &[&one, &one])));
+    goal.assert(&ast::Int::add(&ctx, &[&x, &y]).gt(&ast::Int::mul(&ctx, &[&three, &one])));
+
+    let num_exprs_after = |tactic: &Tactic| {
+        let subgoals: Vec<_> = tactic.apply(&goal, None).unwrap().list_subgoals().collect();
+        assert_eq!(subgoals.len(), 1);
+        subgoals[0].get_num_expr()
+    };
+
+    let simplify = Tactic::new(&ctx, "simplify");
+    let propagate_values = Tactic::new(&ctx, "propagate-values");
+    let combined = num_exprs_after(&simplify.and_then(&propagate_values));
+    assert!(combined < num_exprs_after(&simplify));
+    assert!(combined < num_exprs_after(&propagate_values));
+
+    let repeated = num_exprs_after(&Tactic::repeat(
+        &ctx,
+        &simplify.or_else(&propagate_values),
+        10,
+    ));
+    assert!(repeated <= num_exprs_after(&simplify));
+}
+
+#[test]
+fn test_solver_from_qfbv_tactic() {
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+
+    let solver = Tactic::new(&ctx, "qfbv").solver();
+    let x = ast::BV::new_const(&ctx, "x", 8);
+    let y = ast::BV::new_const(&ctx, "y", 8);
+    solver.assert(&x.bvadd(&y)._eq(&ast::BV::from_u64(&ctx, 3, 8)));
+    solver.assert(&x.bvmul(&y)._eq(&ast::BV::from_u64(&ctx, 2, 8)));
+    solver.assert(&x.bvugt(&y));
+    assert_eq!(solver.check(), SatResult::Sat);
+
+    let model = solver.get_model().unwrap();
+    let x_val = model.eval(&x, true).unwrap().as_u64().unwrap();
+    let y_val = model.eval(&y, true).unwrap().as_u64().unwrap();
+    assert_eq!(x_val.wrapping_add(y_val) % 256, 3);
+    assert_eq!(x_val.wrapping_mul(y_val) % 256, 2);
+    assert!(x_val > y_val);
+}
+
+#[test]
+fn test_probe_size_and_branching() {
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+
+    let x = ast::Int::new_const(&ctx, "x");
+    let y = ast::Int::new_const(&ctx, "y");
+    let zero = ast::Int::from_i64(&ctx, 0);
+    let g = Goal::new(&ctx, false, false, false);
+    g.assert(&x.gt(&zero));
+    g.assert(&y.gt(&zero));
+    g.assert(&x.lt(&y));
+
+    assert_eq!(Probe::new(&ctx, "size").apply(&g), 3.0);
+    let num_consts = Probe::new(&ctx, "num-consts");
+    assert_eq!(num_consts.apply(&g), 2.0);
+
+    let few_consts = num_consts.le(&Probe::constant(&ctx, 5.0));
+    assert_eq!(few_consts.apply(&g), 1.0);
+    let tactic = Tactic::cond(
+        &ctx,
+        &few_consts,
+        &Tactic::create_skip(&ctx),
+        &Tactic::create_fail(&ctx),
+    );
+    assert!(tactic.apply(&g, None).is_ok());
+
+    let many_consts = num_consts.gt(&Probe::constant(&ctx, 5.0));
+    let tactic = Tactic::cond(
+        &ctx,
+        &many_consts,
+        &Tactic::create_skip(&ctx),
+        &Tactic::create_fail(&ctx),
+    );
+    assert!(tactic.apply(&g, None).is_err());
+}
+
+#[test]
+fn test_config_typed_params() {
+    let mut cfg = Config::new();
+    cfg.set_bool_param_value("proof", true);
+    cfg.set_model_generation(true);
+    cfg.set_u32_param_value("timeout", 60_000);
+    let ctx = Context::new(&cfg);
+    let solver = Solver::new(&ctx);
+
+    let p = ast::Bool::new_const(&ctx, "p");
+    solver.assert(&p);
+    solver.assert(&p.not());
+    assert_eq!(solver.check(), SatResult::Unsat);
+    assert!(solver.get_proof().is_some());
+}
+
+#[test]
+fn test_context_check_error() {
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+    let solver = Solver::new(&ctx);
+
+    solver.from_string("(declare-const x Int) (assert (> x 0))");
+    assert_eq!(ctx.check_error(), Ok(()));
+
+    solver.from_string("(declare-const y Int) (assert (+ y true))");
+    let err = ctx.check_error().unwrap_err();
+    assert_ne!(err.code(), ErrorCode::OK);
+    assert!(!err.message().is_empty());
+    assert_eq!(err.to_string(), err.message());
+}
+
+#[test]
+fn test_ast_vector_push_get() {
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+
+    let x = ast::Int::new_const(&ctx, "x");
+    let p = ast::Bool::new_const(&ctx, "p");
+    let mut vector = AstVector::new(&ctx);
+    assert!(vector.is_empty());
+
+    vector.push(&x);
+    vector.push(&p);
+    assert_eq!(vector.len(), 2);
+    assert_eq!(vector.get(0).unwrap().as_int(), Some(x));
+    assert_eq!(vector.get(1).unwrap().as_bool(), Some(p));
+    assert!(vector.get(2).is_none());
+    assert_eq!(vector.iter().count(), 2);
+
+    let mut copy = vector.clone();
+    copy.push(&ast::Int::from_i64(&ctx, 1));
+    assert_eq!(copy.len(), 3);
+    assert_eq!(vector.len(), 2);
+}
+
+#[test]
+fn test_ast_map_insert_find() {
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+
+    let x = ast::Int::new_const(&ctx, "x");
+    let y = ast::Int::new_const(&ctx, "y");
+    let five = ast::Int::from_i64(&ctx, 5);
+    let mut map = AstMap::new(&ctx);
+    assert!(map.is_empty());
+
+    map.insert(&x, &five);
+    assert_eq!(map.len(), 1);
+    assert!(map.contains(&x));
+    assert_eq!(map.find(&x).unwrap().as_int(), Some(five));
+    assert!(map.find(&y).is_none());
+
+    map.insert(&y, &x);
+    let keys = map.keys();
+    assert_eq!(keys.len(), 2);
+    assert!(keys.iter().any(|k| k.as_int() == Some(y.clone())));
+
+    map.erase(&x);
+    assert!(!map.contains(&x));
+    assert_eq!(map.len(), 1);
+}
+
+#[test]
+fn test_context_update_timeout() {
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+
+    ctx.update_param_value("timeout", "50");
+    let solver = Solver::new(&ctx);
+    assert_hard_problem(&ctx, &solver);
+    assert_eq!(solver.check(), SatResult::Unknown);
+    assert_eq!(solver.get_reason_unknown(), Some("timeout".to_owned()));
+}
+
+#[test]
+fn test_rec_func_def_sum_to() {
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+
+    let sum_to = RecFuncDecl::new(&ctx, "sum_to", &[&Sort::int(&ctx)], &Sort::int(&ctx));
+    assert_eq!(sum_to.arity(), 1);
+    let n = ast::Int::new_const(&ctx, "n");
+    let n_minus_1 = ast::Int::sub(&ctx, &[&n, &ast::Int::from_i64(&ctx, 1)]);
+    let rec = sum_to.apply(&[&n_minus_1.into()]).as_int().unwrap();
+    let body = n.le(&ast::Int::from_i64(&ctx, 0)).ite(
+        &ast::Int::from_i64(&ctx, 0),
+        &ast::Int::add(&ctx, &[&n, &rec]),
+    );
+    sum_to.add_def(&[&n.into()], &body);
+
+    let sum_to_10 = sum_to
+        .apply(&[&ast::Int::from_i64(&ctx, 10).into()])
+        .as_int()
+        .unwrap();
+    let solver = Solver::new(&ctx);
+    solver.assert(&sum_to_10._eq(&ast::Int::from_i64(&ctx, 55)).not());
+    assert_eq!(solver.check(), SatResult::Unsat);
+}
+
+#[test]
+fn test_int_mod_rem_power() {
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+    let int = |i| ast::Int::from_i64(&ctx, i);
+
+    let solver = Solver::new(&ctx);
+    solver.assert(&int(-7).modulo(&int(3))._eq(&int(2)).not());
+    assert_eq!(solver.check(), SatResult::Unsat);
+
+    assert_eq!(int(-7).modulo(&int(3)).simplify().as_i64(), Some(2));
+    assert_eq!(int(-7).div(&int(3)).simplify().as_i64(), Some(-3));
+    assert_eq!(int(-7).rem(&int(3)).simplify().as_i64(), Some(2));
+    assert_eq!(int(7).modulo(&int(-3)).simplify().as_i64(), Some(1));
+    assert_eq!(int(7).rem(&int(-3)).simplify().as_i64(), Some(-1));
+    assert_eq!(int(2).power(&int(10)).simplify().as_i64(), Some(1024));
+}
+
+#[test]
+fn test_numerals_from_str() {
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+
+    let digits = "1234567890123456789012345678901234567890";
+    let big = ast::Int::from_str(&ctx, digits).unwrap();
+    let x = ast::Int::new_const(&ctx, "x");
+    let solver = Solver::new(&ctx);
+    solver.assert(&x._eq(&big));
+    assert_eq!(solver.check(), SatResult::Sat);
+    let model = solver.get_model().unwrap();
+    assert_eq!(model.eval(&x, true).unwrap().to_string(), digits);
+    assert!(ast::Int::from_str(&ctx, "12abc").is_none());
+
+    let three_quarters = ast::Real::from_str(&ctx, "3/4").unwrap();
+    assert_eq!(three_quarters.as_real(), Some((3, 4)));
+    assert_eq!(
+        ast::Real::from_str(&ctx, "0.125").unwrap().as_real(),
+        Some((1, 8))
+    );
+    assert!(ast::Real::from_str(&ctx, "three").is_none());
+    assert!(ast::Real::from_str(&ctx, "1\0").is_none());
+}
+
+#[test]
+fn test_numeral_as_string() {
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+
+    let x = ast::Real::new_const(&ctx, "x");
+    let solver = Solver::new(&ctx);
+    solver.assert(
+        &ast::Real::mul(&ctx, &[&x, &ast::Real::from_real(&ctx, 3, 1)])
+            ._eq(&ast::Real::from_real(&ctx, 1, 1)),
+    );
+    assert_eq!(solver.check(), SatResult::Sat);
+    let model = solver.get_model().unwrap();
+    assert_eq!(
+        model.eval(&x, true).unwrap().as_string(),
+        Some("1/3".to_owned())
+    );
+    assert_eq!(x.as_string(), None);
+
+    let digits = "-1234567890123456789012345678901234567890";
+    let big = ast::Int::from_str(&ctx, digits).unwrap();
+    assert_eq!(big.as_string(), Some(digits.to_owned()));
+    assert_eq!(ast::Int::new_const(&ctx, "y").as_string(), None);
+}
+
+#[test]
+fn test_real_approx() {
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+
+    let x = ast::Real::new_const(&ctx, "x");
+    let solver = Solver::new(&ctx);
+    solver.assert(&ast::Real::mul(&ctx, &[&x, &x])._eq(&ast::Real::from_real(&ctx, 2, 1)));
+    solver.assert(&x.gt(&ast::Real::from_real(&ctx, 0, 1)));
+    assert_eq!(solver.check(), SatResult::Sat);
+    let model = solver.get_model().unwrap();
+    let root = model.eval(&x, true).unwrap();
+    assert!(root.approx(10).unwrap().starts_with("1.414"));
+    assert_eq!(root.approx(3), Some("1.414?".to_owned()));
+
+    assert_eq!(
+        ast::Real::from_real(&ctx, 1, 4).approx(5),
+        Some("0.25".to_owned())
+    );
+    assert_eq!(x.approx(5), None);
+}
+
+#[test]
+fn test_solver_check_timeout() {
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+    let solver = Solver::new(&ctx);
+
+    assert_hard_problem(&ctx, &solver);
+    assert_eq!(solver.check_timeout(50), SatResult::Unknown);
+
+    solver.reset();
+    let x = ast::Int::new_const(&ctx, "x");
+    solver.assert(&x.gt(&ast::Int::from_i64(&ctx, 0)));
+    assert_eq!(solver.check_timeout(60_000), SatResult::Sat);
+}
+
+#[test]
+fn test_solver_reason_unknown_mentions_limit() {
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+
+    let solver = Solver::new(&ctx);
+    assert_hard_problem(&ctx, &solver);
+    assert_eq!(solver.check_timeout(50), SatResult::Unknown);
+    let reason = solver.get_reason_unknown().unwrap();
+    assert!(reason.contains("timeout"), "{}", reason);
+
+    let solver = Solver::new(&ctx);
+    let mut params = Params::new(&ctx);
+    params.set_u32("rlimit", 100);
+    solver.set_params(&params);
+    assert_hard_problem(&ctx, &solver);
+    assert_eq!(solver.check(), SatResult::Unknown);
+    let reason = solver.get_reason_unknown().unwrap();
+    assert!(reason.contains("limit"), "{}", reason);
+}
+
+#[test]
+fn test_config_clone_sibling_contexts() {
+    let mut cfg = Config::new();
+    cfg.set_proof_generation(true);
+    let cfg2 = cfg.clone();
+    drop(cfg);
+
+    let ctx1 = Context::new(&cfg2);
+    let ctx2 = Context::new(&cfg2.clone());
+
+    let p = ast::Bool::new_const(&ctx1, "p");
+    let contradiction = ast::Bool::and(&ctx1, &[&p, &p.not()]);
+    let translated = contradiction.translate(&ctx2);
+    assert_eq!(translated.get_ctx(), &ctx2);
+
+    let solver = Solver::new(&ctx2);
+    solver.assert(&translated);
+    assert_eq!(solver.check(), SatResult::Unsat);
+    // Proof generation was carried over by the cloned config.
+    assert!(solver.get_proof().is_some());
+}
+
+#[test]
+fn test_ast_translate_into_solver() {
+    let cfg = Config::new();
+    let src = Context::new(&cfg);
+    let dest = Context::new(&cfg);
+
+    let x = ast::Int::new_const(&src, "x");
+    let constraint = ast::Bool::and(
+        &src,
+        &[
+            &x.gt(&ast::Int::from_i64(&src, 4)),
+            &x.lt(&ast::Int::from_i64(&src, 6)),
+        ],
+    );
+
+    let translated = constraint.translate(&dest);
+    assert_eq!(translated.get_ctx(), &dest);
+    assert_eq!(translated.to_string(), constraint.to_string());
+
+    let solver = Solver::new(&dest);
+    solver.assert(&translated);
+    assert_eq!(solver.check(), SatResult::Sat);
+    let model = solver.get_model().unwrap();
+    let x_dest = x.translate(&dest);
+    assert_eq!(model.eval(&x_dest, true).unwrap().as_i64(), Some(5));
+}
+
+#[test]
+fn test_solver_cube() {
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+    let solver = Solver::new(&ctx);
+
+    let bools: Vec<_> = (0..4)
+        .map(|i| ast::Bool::new_const(&ctx, format!("b{}", i)))
+        .collect();
+    solver.assert(&ast::Bool::or(&ctx, &[&bools[0], &bools[1]]));
+    solver.assert(&ast::Bool::or(&ctx, &[&bools[1].not(), &bools[2]]));
+    solver.assert(&ast::Bool::or(&ctx, &[&bools[2].not(), &bools[3]]));
+
+    let mut vars = AstVector::new(&ctx);
+    for b in &bools {
+        vars.push(b);
+    }
+    let mut cubes = vec![];
+    loop {
+        let cube = solver.cube(&mut vars, u32::MAX);
+        if cube.len() == 1 && cube[0].as_bool() == Some(false) {
+            break;
+        }
+        assert!(cubes.len() < 16, "cubing did not terminate");
+        // An empty cube means the remaining search space is not split
+        // further, so it is the last cube.
+        let last = cube.is_empty();
+        cubes.push(cube);
+        if last {
+            break;
+        }
+    }
+    assert!(cubes.iter().any(|cube| !cube.is_empty()));
+
+    // Together the cubes cover the search space, so some cube is consistent
+    // with the satisfiable assertions.
+    assert!(cubes
+        .iter()
+        .any(|cube| solver.check_assumptions(cube) == SatResult::Sat));
+}
+
+#[test]
+fn test_model_summary() {
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+
+    let x = ast::Int::new_const(&ctx, "x");
+    let f = FuncDecl::new(&ctx, "f", &[&Sort::int(&ctx)], &Sort::int(&ctx));
+    let solver = Solver::new(&ctx);
+    solver.assert(&x._eq(&ast::Int::from_i64(&ctx, 3)));
+    solver.assert(
+        &f.apply(&[&x])
+            .as_int()
+            .unwrap()
+            ._eq(&ast::Int::from_i64(&ctx, 7)),
+    );
+    assert_eq!(solver.check(), SatResult::Sat);
+
+    let model = solver.get_model().unwrap();
+    assert_eq!(
+        model.summary(),
+        ModelSummary {
+            num_consts: 1,
+            num_funcs: 1,
+            num_sorts: 0,
+        }
+    );
+}
+
+#[test]
+fn test_uninterpreted_sort_constants() {
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+
+    let person = Sort::uninterpreted(&ctx, "Person".into());
+    assert_eq!(person, Sort::uninterpreted(&ctx, "Person".into()));
+    assert_ne!(person, Sort::uninterpreted(&ctx, "Place".into()));
+    assert_eq!(person.kind(), SortKind::Uninterpreted);
+
+    let alice = FuncDecl::new(&ctx, "alice", &[], &person).apply(&[]);
+    let bob = FuncDecl::new(&ctx, "bob", &[], &person).apply(&[]);
+    let carol = FuncDecl::new(&ctx, "carol", &[], &person).apply(&[]);
+    assert_eq!(alice.get_sort(), person);
+
+    let solver = Solver::new(&ctx);
+    solver.assert(&alice._eq(&bob).not());
+    solver.assert(&ast::Bool::or(
+        &ctx,
+        &[&carol._eq(&alice), &carol._eq(&bob)],
+    ));
+    assert_eq!(solver.check(), SatResult::Sat);
+
+    let model = solver.get_model().unwrap();
+    let universe = model.get_sort_universe(&person);
+    assert_eq!(universe.len(), 2);
+    let alice_val = model.eval(&alice, true).unwrap();
+    let bob_val = model.eval(&bob, true).unwrap();
+    assert_ne!(alice_val, bob_val);
+    assert!(universe.contains(&alice_val));
+    assert!(universe.contains(&bob_val));
+}
+
+#[test]
+fn test_fresh_const_and_func_decl() {
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+    let int = Sort::int(&ctx);
+
+    let a = ast::Dynamic::fresh_const(&ctx, "v", &int);
+    let b = ast::Dynamic::fresh_const(&ctx, "v", &int);
+    assert_eq!(a.get_sort(), int);
+    assert_ne!(a, b);
+    assert_ne!(a.decl().name(), b.decl().name());
+    assert!(a.decl().name().starts_with('v'));
+
+    let f = FuncDecl::fresh(&ctx, "f", &[&int], &int);
+    let g = FuncDecl::fresh(&ctx, "f", &[&int], &int);
+    assert_ne!(f.name(), g.name());
+    assert_eq!(f.arity(), 1);
+
+    let solver = Solver::new(&ctx);
+    solver.assert(&a._eq(&b).not());
+    solver.assert(&f.apply(&[&a])._eq(&g.apply(&[&a])).not());
+    assert_eq!(solver.check(), SatResult::Sat);
+}
+
+#[test]
+fn test_bool_connectives_truth_tables() {
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+
+    let a = ast::Bool::new_const(&ctx, "a");
+    let b = ast::Bool::new_const(&ctx, "b");
+    let solver = Solver::new(&ctx);
+
+    // (a -> b) <-> (!a || b) is valid, so its negation is unsat.
+    let equivalence = a.implies(&b).iff(&ast::Bool::or(&ctx, &[&a.not(), &b]));
+    solver.assert(&equivalence.not());
+    assert_eq!(solver.check(), SatResult::Unsat);
+
+    for &x in &[false, true] {
+        for &y in &[false, true] {
+            let bx = ast::Bool::from_bool(&ctx, x);
+            let by = ast::Bool::from_bool(&ctx, y);
+            assert_eq!(bx.xor(&by).simplify().as_bool(), Some(x != y));
+            assert_eq!(bx.iff(&by).simplify().as_bool(), Some(x == y));
+            assert_eq!(bx.implies(&by).simplify().as_bool(), Some(!x || y));
+        }
+    }
+}
+
+#[test]
+fn test_bool_atmost_atleast() {
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+
+    let bools: Vec<_> = (0..3)
+        .map(|i| ast::Bool::new_const(&ctx, format!("b{}", i)))
+        .collect();
+    let refs: Vec<_> = bools.iter().collect();
+
+    let solver = Solver::new(&ctx);
+    solver.assert(&ast::Bool::atmost(&ctx, &refs, 1));
+    solver.assert(&ast::Bool::atleast(&ctx, &refs, 1));
+    assert_eq!(solver.check(), SatResult::Sat);
+    let model = solver.get_model().unwrap();
+    let num_true = bools
+        .iter()
+        .filter(|b| model.eval(*b, true).unwrap().as_bool() == Some(true))
+        .count();
+    assert_eq!(num_true, 1);
+
+    for b in &bools {
+        solver.assert(b);
+    }
+    assert_eq!(solver.check(), SatResult::Unsat);
+}
+
+#[test]
+fn test_goal_convert_model() {
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+
+    let x = ast::Int::new_const(&ctx, "x");
+    let y = ast::Int::new_const(&ctx, "y");
+    let z = ast::Int::new_const(&ctx, "z");
+    let goal = Goal::new(&ctx, true, false, false);
+    goal.assert(&x._eq(&ast::Int::add(&ctx, &[&y, &ast::Int::from_i64(&ctx, 1)])));
+    goal.assert(&y._eq(&ast::Int::from_i64(&ctx, 3)));
+    goal.assert(&z.gt(&x));
+
+    let subgoals: Vec<_> = Tactic::new(&ctx, "solve-eqs")
+        .apply(&goal, None)
+        .unwrap()
+        .list_subgoals()
+        .collect();
+    assert_eq!(subgoals.len(), 1);
+    let subgoal = &subgoals[0];
+
+    let solver = Solver::new(&ctx);
+    for formula in subgoal.get_formulas::<ast::Bool>() {
+        solver.assert(&formula);
+    }
+    assert_eq!(solver.check(), SatResult::Sat);
+    let model = solver.get_model().unwrap();
+    assert!(model.eval(&x, false).unwrap().as_i64().is_none());
+
+    let converted = subgoal.convert_model(&model);
+    assert_eq!(converted.eval(&x, true).unwrap().as_i64(), Some(4));
+    assert_eq!(converted.eval(&y, true).unwrap().as_i64(), Some(3));
+    assert!(converted.eval(&z, true).unwrap().as_i64().unwrap() > 4);
+}
+
+#[test]
+fn test_sat_result_predicates_and_display() {
+    assert!(SatResult::Sat.is_sat());
+    assert!(!SatResult::Sat.is_unsat());
+    assert!(!SatResult::Sat.is_unknown());
+    assert!(SatResult::Unsat.is_unsat());
+    assert!(!SatResult::Unsat.is_sat());
+    assert!(SatResult::Unknown.is_unknown());
+    assert!(!SatResult::Unknown.is_sat());
+
+    assert_eq!(SatResult::Sat.to_string(), "sat");
+    assert_eq!(SatResult::Unsat.to_string(), "unsat");
+    assert_eq!(SatResult::Unknown.to_string(), "unknown");
+}
+
+#[test]
+fn test_model_eval_or_simplify() {
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+
+    let x = ast::Int::new_const(&ctx, "x");
+    let y = ast::Int::new_const(&ctx, "y");
+    let solver = Solver::new(&ctx);
+    solver.assert(&x._eq(&ast::Int::from_i64(&ctx, 2)));
+    assert_eq!(solver.check(), SatResult::Sat);
+    let model = solver.get_model().unwrap();
+
+    // `y` is unconstrained, so without completion it stays symbolic.
+    let two = ast::Int::from_i64(&ctx, 2);
+    let expr = ast::Int::add(&ctx, &[&x, &y, &two]);
+    let partial = model.eval_or_simplify(&expr, false);
+    assert!(partial.as_i64().is_none());
+    assert_eq!(
+        partial,
+        ast::Int::add(&ctx, &[&ast::Int::from_i64(&ctx, 4), &y]).simplify()
+    );
+
+    let complete = model.eval_or_simplify(&expr, true);
+    assert!(complete.as_i64().is_some());
+}
+
+#[test]
+fn test_ast_kind_classification() {
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+
+    let a = ast::Int::new_const(&ctx, "a");
+    let b = ast::Int::new_const(&ctx, "b");
+    let five = ast::Int::from_i64(&ctx, 5);
+    assert_eq!(five.kind(), AstKind::Numeral);
+    assert_eq!(ast::Real::from_real(&ctx, 1, 3).kind(), AstKind::Numeral);
+    assert_eq!(ast::Int::add(&ctx, &[&a, &b]).kind(), AstKind::App);
+    assert_eq!(a.kind(), AstKind::App);
+
+    let forall = ast::forall_const(&ctx, &[&a], &[], &a.ge(&a));
+    assert_eq!(forall.kind(), AstKind::Quantifier);
+    assert!(!forall.is_app());
+}
+
+#[test]
+fn test_quantifier_introspection() {
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+
+    let x = ast::Int::new_const(&ctx, "x");
+    let p = ast::Bool::new_const(&ctx, "p");
+    let body = p.implies(&x.ge(&ast::Int::from_i64(&ctx, 0)));
+    let forall = ast::forall_const(&ctx, &[&x, &p], &[], &body);
+
+    assert!(forall.is_quantifier());
+    assert!(!body.is_quantifier());
+    assert_eq!(forall.quantifier_num_bound(), 2);
+    assert_eq!(
+        forall.quantifier_bound_name(0),
+        Symbol::String("x".to_owned())
+    );
+    assert_eq!(forall.quantifier_bound_sort(0), Sort::int(&ctx));
+    assert_eq!(
+        forall.quantifier_bound_name(1),
+        Symbol::String("p".to_owned())
+    );
+    assert_eq!(forall.quantifier_bound_sort(1), Sort::bool(&ctx));
+
+    let quantifier_body = forall.quantifier_body();
+    assert_eq!(quantifier_body.sort_kind(), SortKind::Bool);
+    assert_eq!(quantifier_body.num_children(), 2);
+    assert_eq!(quantifier_body.nth_child(0).unwrap().kind(), AstKind::Var);
+}
@@ -0,0 +1,33 @@
+//! The Z3 log is process-global, so these tests live in their own binary.
+
+extern crate z3;
+use z3::*;
+
+#[test]
+fn test_open_close_log() {
+    let path = std::env::temp_dir().join(format!("z3-rs-test-{}.log", std::process::id()));
+    let path_str = path.to_str().unwrap();
+
+    // Tracing is a no-op unless Z3 was built in debug mode.
+    enable_trace("arith");
+    disable_trace("arith");
+
+    assert!(open_log(path_str));
+    {
+        let cfg = Config::new();
+        let ctx = Context::new(&cfg);
+        let solver = Solver::new(&ctx);
+        solver.assert(&ast::Bool::new_const(&ctx, "logged_const"));
+        assert_eq!(solver.check(), SatResult::Sat);
+    }
+    close_log();
+    // Calls made after closing the log must not be recorded.
+    let ctx = Context::new(&Config::new());
+    ast::Bool::new_const(&ctx, "unlogged_const");
+
+    let log = std::fs::read_to_string(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    // Symbols are logged as `$ |name|` by the call that creates them.
+    assert!(log.contains("$ |logged_const|"), "{}", log);
+    assert!(!log.contains("unlogged_const"), "{}", log);
+}
@@ -161,3 +161,138 @@ fn test_optimize_assert_soft_and_get_objectives() {
         );
     }
 }
+
+#[test]
+fn test_optimize_maximize_minimize() {
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+    let opt = Optimize::new(&ctx);
+
+    let x = ast::Int::new_const(&ctx, "x");
+    let y = ast::Int::new_const(&ctx, "y");
+    opt.assert(&x.le(&ast::Int::from_i64(&ctx, 10)));
+    opt.assert(&y.ge(&ast::Int::from_i64(&ctx, -3)));
+    let x_idx = opt.maximize(&x);
+    let y_idx = opt.minimize(&y);
+    assert_ne!(x_idx, y_idx);
+
+    assert_eq!(opt.check(&[]), SatResult::Sat);
+    let model = opt.get_model().unwrap();
+    assert_eq!(model.eval(&x, true).unwrap().as_i64(), Some(10));
+    assert_eq!(model.eval(&y, true).unwrap().as_i64(), Some(-3));
+}
+
+#[test]
+fn test_optimize_assert_soft_prefers_heavier_weight() {
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+    let opt = Optimize::new(&ctx);
+
+    let a = ast::Bool::new_const(&ctx, "a");
+    let light = opt.assert_soft(&a, 1, Some(Symbol::from("g")));
+    let heavy = opt.assert_soft(&a.not(), 5, Some(Symbol::from("g")));
+    assert_eq!(light, heavy);
+    let other = opt.assert_soft(&a, 1, None);
+    assert_ne!(other, light);
+
+    assert_eq!(opt.check(&[]), SatResult::Sat);
+    let model = opt.get_model().unwrap();
+    assert_eq!(model.eval(&a, true).unwrap().as_bool(), Some(false));
+}
+
+#[test]
+fn test_optimize_get_lower_upper() {
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+    let opt = Optimize::new(&ctx);
+
+    let x = ast::Int::new_const(&ctx, "x");
+    opt.assert(&x.le(&ast::Int::from_i64(&ctx, 42)));
+    let idx = opt.maximize(&x);
+    assert_eq!(opt.check(&[]), SatResult::Sat);
+
+    let model = opt.get_model().unwrap();
+    let value = model.eval(&x, true).unwrap();
+    assert_eq!(opt.get_upper(idx).as_int().unwrap(), value);
+    assert_eq!(opt.get_lower(idx).as_int().unwrap(), value);
+}
+
+#[test]
+fn test_optimize_push_pop() {
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+    let opt = Optimize::new(&ctx);
+
+    let x = ast::Int::new_const(&ctx, "x");
+    opt.assert(&x.le(&ast::Int::from_i64(&ctx, 10)));
+    opt.maximize(&x);
+
+    opt.push();
+    opt.assert(&x.gt(&ast::Int::from_i64(&ctx, 10)));
+    assert_eq!(opt.check(&[]), SatResult::Unsat);
+    opt.pop();
+
+    assert_eq!(opt.check(&[]), SatResult::Sat);
+    let model = opt.get_model().unwrap();
+    assert_eq!(model.eval(&x, true).unwrap().as_i64(), Some(10));
+}
+
+#[test]
+fn test_optimize_to_string_from_string_round_trip() {
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+    let opt = Optimize::new(&ctx);
+
+    let x = ast::Int::new_const(&ctx, "x");
+    let y = ast::Int::new_const(&ctx, "y");
+    let ten = ast::Int::from_i64(&ctx, 10);
+    opt.assert(&ast::Int::add(&ctx, &[&x, &y]).le(&ten));
+    opt.assert(&y.ge(&ast::Int::from_i64(&ctx, 0)));
+    opt.assert_soft(&y.ge(&ast::Int::from_i64(&ctx, 3)), 1, None);
+    opt.maximize(&x);
+    assert_eq!(opt.check(&[]), SatResult::Sat);
+    let model = opt.get_model().unwrap();
+    let x_max = model.eval(&x, true).unwrap().as_i64();
+    let y_val = model.eval(&y, true).unwrap().as_i64();
+
+    let parsed = Optimize::new(&ctx);
+    parsed.from_string(opt.to_string());
+    assert_eq!(parsed.get_objectives().len(), opt.get_objectives().len());
+    assert_eq!(parsed.check(&[]), SatResult::Sat);
+    let model = parsed.get_model().unwrap();
+    assert_eq!(model.eval(&x, true).unwrap().as_i64(), x_max);
+    assert_eq!(model.eval(&y, true).unwrap().as_i64(), y_val);
+}
+
+#[test]
+fn test_optimize_get_statistics() {
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+    let opt = Optimize::new(&ctx);
+
+    let x = ast::Int::new_const(&ctx, "x");
+    opt.assert(&x.le(&ast::Int::from_i64(&ctx, 10)));
+    opt.maximize(&x);
+    assert_eq!(opt.check(&[]), SatResult::Sat);
+
+    let stats = opt.get_statistics();
+    assert!(stats.entries().count() > 0);
+    assert!(stats.value("rlimit count").is_some());
+}
+
+#[test]
+fn test_optimize_get_assertions_and_objectives() {
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+    let opt = Optimize::new(&ctx);
+
+    let x = ast::Int::new_const(&ctx, "x");
+    let bound = x.ge(&ast::Int::from_i64(&ctx, -10));
+    opt.assert(&bound);
+    assert_eq!(opt.get_assertions(), vec![bound]);
+
+    opt.minimize(&x);
+    let objectives = opt.get_objectives();
+    assert_eq!(objectives.len(), 1);
+    assert_eq!(objectives[0].as_int(), Some(x));
+}